@@ -1,3 +1,4 @@
+// eren_vulkan은 이 파일을 빌드 시 SPIR-V로 변환해 사용 (eren_vulkan/build.rs)
 // uniform buffer 정의 (binding 0, group 0)
 struct Uniforms {
     model : mat4x4<f32>,
@@ -8,16 +9,24 @@ struct Uniforms {
 @group(0) @binding(0)
 var<uniform> ubo : Uniforms;
 
+// 텍스처와 샘플러 (binding 1, 2, group 0), 텍스처가 없는 장면은 1x1 흰색 텍스처 사용
+@group(0) @binding(1)
+var texSampler : texture_2d<f32>;
+@group(0) @binding(2)
+var texSamplerState : sampler;
+
 // 정점 입력 구조체 (vertex buffer layout에서 제공)
 struct VertexInput {
-    @location(0) inPosition : vec2<f32>,
+    @location(0) inPosition : vec3<f32>,
     @location(1) inColor    : vec3<f32>,
+    @location(2) inTexCoord : vec2<f32>,
 };
 
 // 정점 출력 구조체
 struct VertexOutput {
     @builtin(position) position : vec4<f32>,
     @location(0) fragColor : vec3<f32>,
+    @location(1) fragTexCoord : vec2<f32>,
 };
 
 @vertex
@@ -25,14 +34,16 @@ fn vs_main(input : VertexInput) -> VertexOutput {
     var output : VertexOutput;
     
     // GLSL과 동일한 방식의 모델-뷰-프로젝션 변환
-    let pos = vec4<f32>(input.inPosition, 0.0, 1.0);
+    let pos = vec4<f32>(input.inPosition, 1.0);
     output.position = ubo.proj * ubo.view * ubo.model * pos;
     
     output.fragColor = input.inColor;
+    output.fragTexCoord = input.inTexCoord;
     return output;
 }
 
 @fragment
-fn fs_main(@location(0) fragColor : vec3<f32>) -> @location(0) vec4<f32> {
-    return vec4<f32>(fragColor, 1.0);
+fn fs_main(input : VertexOutput) -> @location(0) vec4<f32> {
+    let texColor = textureSample(texSampler, texSamplerState, input.fragTexCoord);
+    return vec4<f32>(input.fragColor * texColor.rgb, texColor.a);
 }
@@ -0,0 +1,47 @@
+// 플랫폼별 asset source는 eren_common에 있고, 여기서는 이 크레이트의 asset 위치만 정함
+pub use eren_common::asset::*;
+
+// 앱 패키지의 asset 디렉터리, iOS 번들에는 같은 디렉터리가 assets/로 복사됨
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+const DEFAULT_ASSET_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/android/app/src/main/assets");
+
+// 파일 시스템이 없는 환경과 테스트를 위해 asset 디렉터리와 같은 파일을 바이너리에 포함
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const EMBEDDED_ASSETS: [EmbeddedAsset; 4] = [
+    (
+        "android_robot.png",
+        include_bytes!("../android/app/src/main/assets/android_robot.png"),
+    ),
+    (
+        "shaders/shader.wgsl",
+        include_bytes!("../android/app/src/main/assets/shaders/shader.wgsl"),
+    ),
+    (
+        "models/sphere.gltf",
+        include_bytes!("../android/app/src/main/assets/models/sphere.gltf"),
+    ),
+    (
+        "models/sphere.bin",
+        include_bytes!("../android/app/src/main/assets/models/sphere.bin"),
+    ),
+];
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub fn embedded_asset_source() -> MemoryAssetSource {
+    MemoryAssetSource::embedded(&EMBEDDED_ASSETS)
+}
+
+#[cfg(any(target_os = "android", target_os = "ios"))]
+pub fn default_asset_source() -> Result<Box<dyn AssetSource>, AssetLoadError> {
+    eren_common::asset::default_asset_source()
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn default_asset_source() -> Result<Box<dyn AssetSource>, AssetLoadError> {
+    eren_common::asset::default_asset_source(&EMBEDDED_ASSETS)
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+pub fn default_asset_source() -> Result<Box<dyn AssetSource>, AssetLoadError> {
+    eren_common::asset::default_asset_source(DEFAULT_ASSET_DIR)
+}
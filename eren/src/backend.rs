@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use eren_render_shared::{adapter::Adapter, device::Device, instance::Instance, surface::Surface};
+use thiserror::Error;
+use winit::window::Window;
+
+// 앞에서부터 차례로 시도할 백엔드 (Android 에뮬레이터처럼 Vulkan이 동작하지 않으면 GL/GLES로 대체)
+#[cfg(not(any(target_arch = "wasm32", target_os = "ios", target_os = "macos")))]
+const BACKEND_CANDIDATES: [(&str, wgpu::Backends); 2] = [
+    ("Vulkan", wgpu::Backends::VULKAN),
+    ("GL", wgpu::Backends::GL),
+];
+
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+const BACKEND_CANDIDATES: [(&str, wgpu::Backends); 2] =
+    [("Metal", wgpu::Backends::METAL), ("GL", wgpu::Backends::GL)];
+
+#[cfg(target_arch = "wasm32")]
+const BACKEND_CANDIDATES: [(&str, wgpu::Backends); 2] = [
+    ("WebGPU", wgpu::Backends::BROWSER_WEBGPU),
+    ("WebGL", wgpu::Backends::GL),
+];
+
+#[derive(Debug, Error)]
+pub enum BackendSelectionError {
+    #[error("No usable backend found: {}", .0.join("; "))]
+    NoUsableBackend(Vec<String>),
+}
+
+// 선택된 백엔드로 만든 wgpu 리소스
+pub struct RenderBackend<'a> {
+    pub instance: Instance,
+    pub surface: Surface<'a>,
+    pub adapter: Adapter,
+    pub device: Device,
+}
+
+impl<'a> RenderBackend<'a> {
+    // WGPU_BACKEND 환경 변수가 있으면 해당 백엔드만 시도
+    pub async fn new(
+        window: Arc<Window>,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, BackendSelectionError> {
+        let allowed = wgpu::Backends::from_env().unwrap_or(wgpu::Backends::all());
+        let mut rejections = Vec::new();
+
+        for (name, backends) in BACKEND_CANDIDATES {
+            if !allowed.contains(backends) {
+                log::debug!("Skipping {} backend: not allowed by WGPU_BACKEND", name);
+                continue;
+            }
+
+            match Self::try_backend(window.clone(), backends, width, height).await {
+                Ok(backend) => {
+                    log::info!("Using {} backend", name);
+                    return Ok(backend);
+                }
+                Err(reason) => {
+                    log::warn!("{} backend rejected: {}", name, reason);
+                    rejections.push(format!("{}: {}", name, reason));
+                }
+            }
+        }
+
+        Err(BackendSelectionError::NoUsableBackend(rejections))
+    }
+
+    async fn try_backend(
+        window: Arc<Window>,
+        backends: wgpu::Backends,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, String> {
+        let instance = Instance::with_backends(window, backends).await;
+
+        let surface =
+            Surface::new(&instance).map_err(|e| format!("Failed to create surface: {:?}", e))?;
+
+        let adapter = Adapter::new(&instance, &surface)
+            .await
+            .map_err(|e| format!("Failed to request adapter: {:?}", e))?;
+
+        // MSAA 샘플 수를 adapter가 실제로 지원하는 범위까지 확인할 수 있도록 요청
+        // PIPELINE_CACHE는 Vulkan 백엔드에서만 지원되며, 없으면 캐시 없이 파이프라인을 만듦
+        let required_features = adapter.features()
+            & (wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES
+                | wgpu::Features::PIPELINE_CACHE);
+
+        let device = Device::with_features(&adapter, &surface, width, height, required_features)
+            .await
+            .map_err(|e| format!("Failed to request device: {:?}", e))?;
+
+        Ok(Self {
+            instance,
+            surface,
+            adapter,
+            device,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
+// 렌더 대상과 같은 크기의 깊이 버퍼, 대상 크기가 바뀌면 다시 만들어야 함
+pub struct DepthTexture {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+}
+
+impl DepthTexture {
+    pub fn new(device: &wgpu::Device, width: u32, height: u32, sample_count: u32) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Depth Texture"),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count,
+            dimension: wgpu::TextureDimension::D2,
+            format: DEPTH_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        Self { texture, view }
+    }
+
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    pub fn matches(&self, width: u32, height: u32) -> bool {
+        self.texture.width() == width.max(1) && self.texture.height() == height.max(1)
+    }
+}
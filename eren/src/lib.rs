@@ -1,20 +1,37 @@
 use std::sync::Arc;
 
+use eren_common::size::SurfaceSize;
 use eren_render_shared::{adapter::Adapter, device::Device, instance::Instance, surface::Surface};
 use eren_window::window::{WindowConfig, WindowEventHandler, WindowLifecycle};
-use winit::window::Window;
+use winit::{dpi::PhysicalSize, window::Window};
 
 #[cfg(target_os = "android")]
 use winit::platform::android::activity::AndroidApp;
 
+mod asset;
+mod backend;
+mod depth;
+mod golden;
+mod msaa;
+mod offscreen;
+mod pipeline_cache;
+mod texture;
+mod typed_buffer;
+
 mod test_vertex_input {
+    pub mod assets;
+    pub mod mesh;
     pub mod render_pass;
     pub mod renderer;
     pub mod ubo;
     pub mod vertex;
 }
 
-use crate::test_vertex_input::renderer::TestRenderer;
+use crate::{
+    backend::RenderBackend,
+    pipeline_cache::PipelineCache,
+    test_vertex_input::{assets::TestAssets, mesh::TestScene, renderer::TestRenderer},
+};
 
 pub fn init_logger() {
     #[cfg(target_arch = "wasm32")]
@@ -35,12 +52,14 @@ pub fn init_logger() {
 
     #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
     {
-        env_logger::init();
+        // 테스트에서 여러 번 호출될 수 있으므로 이미 초기화되었으면 무시
+        let _ = env_logger::try_init();
     }
 }
 
 struct TestWindowEventHandler<'a> {
     window: Arc<Window>,
+    size: SurfaceSize,
     _instance: Instance,
     surface: Surface<'a>,
     _adapter: Adapter,
@@ -48,31 +67,65 @@ struct TestWindowEventHandler<'a> {
     renderer: TestRenderer,
 }
 
+impl<'a> TestWindowEventHandler<'a> {
+    fn reconfigure_surface(&mut self) {
+        if self.size.is_empty() {
+            return;
+        }
+
+        self.device.resize_surface(
+            &self.surface,
+            self.size.physical.width,
+            self.size.physical.height,
+        );
+        self.renderer.resize(
+            &self.device,
+            self.size.physical.width,
+            self.size.physical.height,
+        );
+    }
+}
+
 impl<'a> WindowEventHandler for TestWindowEventHandler<'a> {
     async fn new(window: Arc<Window>) -> Self {
         log::debug!("Window created");
 
-        let instance = Instance::new(window.clone()).await;
-        let surface = Surface::new(&instance).unwrap();
-        let adapter = Adapter::new(&instance, &surface).await.unwrap();
+        let size = SurfaceSize::from_window(&window);
+        log::debug!(
+            "Window size: {:?} (logical {:?}, scale factor {})",
+            size.physical,
+            size.logical(),
+            size.scale_factor
+        );
 
-        let window_size = window.inner_size();
-        let scale_factor = window.scale_factor();
-        let device = Device::new(
+        let RenderBackend {
+            instance,
+            surface,
+            adapter,
+            device,
+        } = RenderBackend::new(window.clone(), size.physical.width, size.physical.height)
+            .await
+            .unwrap();
+
+        let sample_count = msaa::select_sample_count(
             &adapter,
-            &surface,
-            window_size.width / scale_factor as u32,
-            window_size.height / scale_factor as u32,
-        )
-        .await
-        .unwrap();
+            &device,
+            device.surface_config.format,
+            msaa::requested_sample_count(),
+        );
+        let asset_source = asset::default_asset_source().unwrap();
+        let assets = TestAssets::load(asset_source.as_ref(), TestScene::Cube)
+            .await
+            .unwrap();
 
-        let renderer = TestRenderer::new(&device);
+        let pipeline_cache = PipelineCache::new(&device, &adapter.get_info());
+        let renderer = TestRenderer::new(&device, &assets, sample_count, &pipeline_cache).unwrap();
 
         log::debug!("Renderer created");
 
         Self {
             window,
+            size,
             _instance: instance,
             surface,
             _adapter: adapter,
@@ -84,39 +137,45 @@ impl<'a> WindowEventHandler for TestWindowEventHandler<'a> {
     fn on_resized(&mut self, width: u32, height: u32) {
         log::debug!("Window resized: {}x{}", width, height);
 
-        let scale_factor = self.window.scale_factor();
-
-        self.device.resize_surface(
-            &self.surface,
-            width / scale_factor as u32,
-            height / scale_factor as u32,
-        );
+        // winit의 resized 이벤트는 물리 픽셀 크기
+        self.size = SurfaceSize::new(PhysicalSize::new(width, height), self.window.scale_factor());
+        self.reconfigure_surface();
     }
 
     fn on_scale_factor_changed(&mut self, scale_factor: f64) {
         log::debug!("Scale factor changed: {}", scale_factor);
 
-        let window_size = self.window.inner_size();
-
-        self.device.resize_surface(
-            &self.surface,
-            window_size.width / scale_factor as u32,
-            window_size.height / scale_factor as u32,
-        );
+        // 이벤트 중에는 inner_size가 아직 이전 크기일 수 있으므로 논리 크기를 유지해 계산
+        self.size = self.size.rescaled(scale_factor);
+        self.reconfigure_surface();
     }
 
     fn on_redraw_requested(&mut self) {
         //log::debug!("Redraw requested");
 
-        let window_size = self.window.inner_size();
-        self.renderer
-            .render(
-                &self.surface,
-                &self.device,
-                window_size.width,
-                window_size.height,
-            )
-            .unwrap();
+        if self.size.is_empty() {
+            return;
+        }
+
+        let result = self
+            .renderer
+            .render(&self.surface, &self.device, self.size.physical);
+
+        match result {
+            Ok(_) => {}
+            // Android 화면 회전, 백그라운드 전환 등으로 surface가 무효화된 경우 재설정
+            Err(e @ (wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated)) => {
+                log::debug!("Reconfiguring surface: {}", e);
+                self.reconfigure_surface();
+            }
+            Err(e @ (wgpu::SurfaceError::Timeout | wgpu::SurfaceError::Other)) => {
+                log::warn!("Skipping frame: {}", e);
+            }
+            Err(e @ wgpu::SurfaceError::OutOfMemory) => {
+                log::error!("Fatal surface error: {}", e);
+                panic!("Fatal surface error: {}", e);
+            }
+        }
     }
 }
 
@@ -126,19 +185,23 @@ impl<'a> Drop for TestWindowEventHandler<'a> {
     }
 }
 
+fn window_config() -> WindowConfig {
+    WindowConfig {
+        width: 800,
+        height: 600,
+        title: "Test Window",
+        canvas_id: None,
+    }
+}
+
 #[cfg(target_os = "android")]
 #[unsafe(no_mangle)]
 fn android_main(app: AndroidApp) {
     init_logger();
 
-    match WindowLifecycle::<TestWindowEventHandler>::new(WindowConfig {
-        width: 800,
-        height: 600,
-        title: "Test Window",
-        canvas_id: None,
-    })
-    .start_event_loop(app)
-    {
+    asset::set_android_app(app.clone());
+
+    match WindowLifecycle::<TestWindowEventHandler>::new(window_config()).start_event_loop(app) {
         Ok(_) => {}
         Err(e) => {
             log::error!("Failed to start event loop: {}", e);
@@ -156,17 +219,78 @@ pub extern "C" fn start_rust_app() {
 
     init_logger();
 
-    match WindowLifecycle::<TestWindowEventHandler>::new(WindowConfig {
-        width: 800,
-        height: 600,
-        title: "Test Window",
-        canvas_id: None,
-    })
-    .start_event_loop()
-    {
+    match WindowLifecycle::<TestWindowEventHandler>::new(window_config()).start_event_loop() {
         Ok(_) => {}
         Err(e) => {
             log::error!("Failed to start event loop: {}", e);
         }
     }
 }
+
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+pub fn start_desktop_app() {
+    init_logger();
+
+    match WindowLifecycle::<TestWindowEventHandler>::new(window_config()).start_event_loop() {
+        Ok(_) => {}
+        Err(e) => {
+            log::error!("Failed to start event loop: {}", e);
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+pub fn render_headless(
+    width: u32,
+    height: u32,
+    path: &std::path::Path,
+) -> Result<(), offscreen::OffscreenError> {
+    use crate::offscreen::{HeadlessContext, OFFSCREEN_FORMAT, OffscreenTarget, save_png};
+
+    init_logger();
+
+    let context = pollster::block_on(HeadlessContext::new())?;
+    let target = OffscreenTarget::new(&context.device, width, height);
+
+    let sample_count = msaa::select_sample_count(
+        &context.adapter,
+        &context.device,
+        OFFSCREEN_FORMAT,
+        msaa::requested_sample_count(),
+    );
+    let asset_source = asset::default_asset_source()?;
+    let assets = pollster::block_on(TestAssets::load(asset_source.as_ref(), TestScene::Cube))?;
+
+    let mut renderer = TestRenderer::with_format(
+        &context.device,
+        &context.queue,
+        OFFSCREEN_FORMAT,
+        &assets,
+        PhysicalSize::new(width, height),
+        sample_count,
+        &context.pipeline_cache,
+    )?;
+    let pixels = renderer.render_offscreen(&context.device, &context.queue, &target)?;
+
+    save_png(path, width, height, &pixels)?;
+    log::debug!("Headless frame saved: {}", path.display());
+
+    Ok(())
+}
+
+// 기준 이미지는 golden/, 불일치 결과는 target/golden/에 저장
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+pub fn run_golden_tests(update: bool) -> Result<(), golden::GoldenError> {
+    init_logger();
+
+    let manifest_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    // 기준 이미지가 파일 시스템의 asset 변경에 영향받지 않도록 바이너리에 포함된 asset 사용
+    golden::run_golden_tests(
+        &asset::embedded_asset_source(),
+        &manifest_dir.join("golden"),
+        &manifest_dir.join("target").join("golden"),
+        golden::DEFAULT_TOLERANCE,
+        update,
+    )
+}
@@ -0,0 +1,123 @@
+use crate::depth::DEPTH_FORMAT;
+
+// 요청할 MSAA 샘플 수 (1, 2, 4 중 하나, 예: EREN_MSAA_SAMPLES=1)
+pub const SAMPLE_COUNT_ENV: &str = "EREN_MSAA_SAMPLES";
+
+pub const DEFAULT_SAMPLE_COUNT: u32 = 4;
+
+// 높은 샘플 수부터 차례로 지원 여부를 확인
+const SAMPLE_COUNTS: [u32; 3] = [4, 2, 1];
+
+pub fn requested_sample_count() -> u32 {
+    let Ok(value) = std::env::var(SAMPLE_COUNT_ENV) else {
+        return DEFAULT_SAMPLE_COUNT;
+    };
+
+    match value.trim().parse() {
+        Ok(count) if SAMPLE_COUNTS.contains(&count) => count,
+        _ => {
+            log::warn!(
+                "Invalid {} value: {:?}, using {}",
+                SAMPLE_COUNT_ENV,
+                value,
+                DEFAULT_SAMPLE_COUNT
+            );
+            DEFAULT_SAMPLE_COUNT
+        }
+    }
+}
+
+// TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES가 켜져 있지 않으면 모든 adapter가 보장하는 기능만 사용 가능
+fn format_features(
+    adapter: &wgpu::Adapter,
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+) -> wgpu::TextureFormatFeatureFlags {
+    if device
+        .features()
+        .contains(wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES)
+    {
+        adapter.get_texture_format_features(format).flags
+    } else {
+        format.guaranteed_format_features(device.features()).flags
+    }
+}
+
+// color 포맷(resolve 포함)과 깊이 포맷이 함께 지원하는 샘플 수 중 requested 이하인 가장 큰 값
+pub fn select_sample_count(
+    adapter: &wgpu::Adapter,
+    device: &wgpu::Device,
+    color_format: wgpu::TextureFormat,
+    requested: u32,
+) -> u32 {
+    let color_features = format_features(adapter, device, color_format);
+    let depth_features = format_features(adapter, device, DEPTH_FORMAT);
+
+    let sample_count = SAMPLE_COUNTS
+        .into_iter()
+        .filter(|&count| count <= requested)
+        .find(|&count| {
+            count == 1
+                || (color_features.sample_count_supported(count)
+                    && color_features
+                        .contains(wgpu::TextureFormatFeatureFlags::MULTISAMPLE_RESOLVE)
+                    && depth_features.sample_count_supported(count))
+        })
+        .unwrap_or(1);
+
+    if sample_count < requested {
+        log::warn!(
+            "MSAA {}x is not supported for {:?}, using {}x",
+            requested,
+            color_format,
+            sample_count
+        );
+    } else {
+        log::debug!("MSAA sample count: {}x", sample_count);
+    }
+
+    sample_count
+}
+
+// 멀티샘플 color 버퍼, 렌더 패스가 끝나면 대상 텍스처로 resolve되고 내용은 버려짐
+pub struct MultisampleTexture {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+}
+
+impl MultisampleTexture {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        width: u32,
+        height: u32,
+        sample_count: u32,
+    ) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Multisample Texture"),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        Self { texture, view }
+    }
+
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    pub fn matches(&self, width: u32, height: u32) -> bool {
+        self.texture.width() == width.max(1) && self.texture.height() == height.max(1)
+    }
+}
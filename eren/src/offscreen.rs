@@ -0,0 +1,225 @@
+use std::path::Path;
+
+use thiserror::Error;
+
+use crate::{
+    asset::AssetLoadError,
+    pipeline_cache::PipelineCache,
+    test_vertex_input::{
+        assets::TestAssetsLoadError, render_pass::TestRenderPassInitializationError,
+    },
+};
+
+pub const OFFSCREEN_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+const BYTES_PER_PIXEL: u32 = 4;
+
+#[derive(Debug, Error)]
+pub enum OffscreenError {
+    #[error("Failed to request adapter: {0}")]
+    RequestAdapter(#[from] wgpu::RequestAdapterError),
+
+    #[error("Failed to request device: {0}")]
+    RequestDevice(#[from] wgpu::RequestDeviceError),
+
+    #[error("Failed to create asset source: {0}")]
+    CreateAssetSource(#[from] AssetLoadError),
+
+    #[error("Failed to load assets: {0}")]
+    LoadAssets(#[from] TestAssetsLoadError),
+
+    #[error("Failed to create renderer: {0}")]
+    CreateRenderer(#[from] TestRenderPassInitializationError),
+
+    #[error("Failed to poll device: {0}")]
+    PollDevice(#[from] wgpu::PollError),
+
+    #[error("Failed to map readback buffer: {0}")]
+    MapReadbackBuffer(#[from] wgpu::BufferAsyncError),
+
+    #[error("Readback buffer mapping callback was dropped")]
+    MapCallbackDropped,
+
+    #[error("Failed to save image: {0}")]
+    SaveImage(#[from] image::ImageError),
+}
+
+// Surface가 없는 환경(CI 등)에서 사용하는 wgpu 디바이스
+pub struct HeadlessContext {
+    pub adapter: wgpu::Adapter,
+    pub device: wgpu::Device,
+    pub queue: wgpu::Queue,
+    pub pipeline_cache: PipelineCache,
+}
+
+impl HeadlessContext {
+    pub async fn new() -> Result<Self, OffscreenError> {
+        // WGPU_BACKEND 등 환경 변수로 백엔드를 강제할 수 있음
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::from_env_or_default());
+
+        let adapter =
+            match wgpu::util::initialize_adapter_from_env_or_default(&instance, None).await {
+                Ok(adapter) => adapter,
+                Err(e) => {
+                    // GPU가 없는 경우 소프트웨어 어댑터(llvmpipe, WARP 등)로 대체
+                    log::warn!("No hardware adapter found ({}), using fallback adapter", e);
+
+                    instance
+                        .request_adapter(&wgpu::RequestAdapterOptions {
+                            power_preference: wgpu::PowerPreference::default(),
+                            force_fallback_adapter: true,
+                            compatible_surface: None,
+                        })
+                        .await?
+                }
+            };
+
+        let adapter_info = adapter.get_info();
+        log::debug!(
+            "Headless adapter: {} ({:?}, {:?})",
+            adapter_info.name,
+            adapter_info.backend,
+            adapter_info.device_type
+        );
+
+        // MSAA 샘플 수를 adapter가 실제로 지원하는 범위까지 확인할 수 있도록 요청
+        // PIPELINE_CACHE는 Vulkan 백엔드에서만 지원됨
+        let required_features = adapter.features()
+            & (wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES
+                | wgpu::Features::PIPELINE_CACHE);
+
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor {
+                label: Some("Headless Device"),
+                required_features,
+                required_limits: wgpu::Limits::downlevel_webgl2_defaults()
+                    .using_resolution(adapter.limits()),
+                ..Default::default()
+            })
+            .await?;
+
+        let pipeline_cache = PipelineCache::new(&device, &adapter_info);
+
+        Ok(Self {
+            adapter,
+            device,
+            queue,
+            pipeline_cache,
+        })
+    }
+}
+
+// Surface 대신 렌더링 대상으로 사용하는 텍스처와 CPU 읽기용 버퍼
+pub struct OffscreenTarget {
+    pub width: u32,
+    pub height: u32,
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    readback_buffer: wgpu::Buffer,
+    padded_bytes_per_row: u32,
+}
+
+impl OffscreenTarget {
+    pub fn new(device: &wgpu::Device, width: u32, height: u32) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Offscreen Texture"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: OFFSCREEN_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        // copy_texture_to_buffer는 행 단위 256바이트 정렬이 필요함
+        let unpadded_bytes_per_row = width * BYTES_PER_PIXEL;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_bytes_per_row = unpadded_bytes_per_row.div_ceil(align) * align;
+
+        let readback_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Offscreen Readback Buffer"),
+            size: (padded_bytes_per_row * height) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        Self {
+            width,
+            height,
+            texture,
+            view,
+            readback_buffer,
+            padded_bytes_per_row,
+        }
+    }
+
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    pub fn copy_to_readback_buffer(&self, encoder: &mut wgpu::CommandEncoder) {
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &self.readback_buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(self.padded_bytes_per_row),
+                    rows_per_image: Some(self.height),
+                },
+            },
+            wgpu::Extent3d {
+                width: self.width,
+                height: self.height,
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+
+    // 제출된 복사 명령이 끝날 때까지 기다린 후 패딩이 제거된 RGBA8 픽셀을 반환
+    pub fn read_pixels(&self, device: &wgpu::Device) -> Result<Vec<u8>, OffscreenError> {
+        let slice = self.readback_buffer.slice(..);
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+
+        device.poll(wgpu::PollType::Wait)?;
+        receiver
+            .recv()
+            .map_err(|_| OffscreenError::MapCallbackDropped)??;
+
+        let unpadded_bytes_per_row = (self.width * BYTES_PER_PIXEL) as usize;
+        let mut pixels = Vec::with_capacity(unpadded_bytes_per_row * self.height as usize);
+
+        {
+            let data = slice.get_mapped_range();
+            for row in data.chunks(self.padded_bytes_per_row as usize) {
+                pixels.extend_from_slice(&row[..unpadded_bytes_per_row]);
+            }
+        }
+
+        self.readback_buffer.unmap();
+
+        Ok(pixels)
+    }
+}
+
+pub fn save_png(path: &Path, width: u32, height: u32, pixels: &[u8]) -> Result<(), OffscreenError> {
+    image::save_buffer(path, pixels, width, height, image::ExtendedColorType::Rgba8)?;
+
+    Ok(())
+}
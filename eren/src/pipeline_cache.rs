@@ -0,0 +1,136 @@
+use std::{path::PathBuf, sync::Mutex};
+
+use eren_common::pipeline_cache::{self, CacheFileLoad, read_cache_file, write_cache_file};
+
+// Android는 asset과 같은 AndroidApp의 내부 저장소를 사용
+#[cfg(target_os = "android")]
+fn default_cache_dir() -> Option<PathBuf> {
+    pipeline_cache::default_cache_dir(crate::asset::android_app()?)
+}
+
+#[cfg(not(target_os = "android"))]
+fn default_cache_dir() -> Option<PathBuf> {
+    pipeline_cache::default_cache_dir(env!("CARGO_PKG_NAME"))
+}
+
+// adapter/드라이버별로 파일에 저장하는 wgpu 파이프라인 캐시
+// PIPELINE_CACHE feature가 없는 백엔드(GL, Metal, WebGPU 등)에서는 아무것도 하지 않음
+pub struct PipelineCache {
+    cache: Option<wgpu::PipelineCache>,
+    path: Option<PathBuf>,
+    identity: String,
+    // 마지막으로 읽거나 저장한 데이터, 바뀌지 않았으면 다시 쓰지 않음
+    saved: Mutex<Vec<u8>>,
+}
+
+impl PipelineCache {
+    pub fn new(device: &wgpu::Device, adapter_info: &wgpu::AdapterInfo) -> Self {
+        let identity = format!(
+            "{}|{:?}|{:04x}|{:04x}|{}|{}",
+            adapter_info.name,
+            adapter_info.backend,
+            adapter_info.vendor,
+            adapter_info.device,
+            adapter_info.driver,
+            adapter_info.driver_info
+        );
+        let disabled = Self {
+            cache: None,
+            path: None,
+            identity: String::new(),
+            saved: Mutex::new(Vec::new()),
+        };
+
+        if !device.features().contains(wgpu::Features::PIPELINE_CACHE) {
+            log::info!(
+                "Pipeline cache is not supported on {:?} backend",
+                adapter_info.backend
+            );
+            return disabled;
+        }
+
+        let Some(file_name) = wgpu::util::pipeline_cache_key(adapter_info) else {
+            return disabled;
+        };
+
+        let path = default_cache_dir().map(|dir| dir.join(file_name));
+
+        let data = match &path {
+            Some(path) => match read_cache_file(path, &identity) {
+                CacheFileLoad::Hit(data) => {
+                    log::info!(
+                        "Pipeline cache hit: {} ({} bytes)",
+                        path.display(),
+                        data.len()
+                    );
+                    Some(data)
+                }
+                CacheFileLoad::Miss => {
+                    log::info!("Pipeline cache miss: {}", path.display());
+                    None
+                }
+                CacheFileLoad::Invalidated(reason) => {
+                    log::info!(
+                        "Pipeline cache invalidated ({}): {}",
+                        reason,
+                        path.display()
+                    );
+                    let _ = std::fs::remove_file(path);
+                    None
+                }
+            },
+            None => {
+                log::warn!("No directory for pipeline cache, cache will not be saved");
+                None
+            }
+        };
+
+        // 같은 adapter/드라이버에서 get_data로 얻은 데이터만 전달하며,
+        // wgpu와 드라이버가 헤더를 다시 검사해 맞지 않으면 빈 캐시로 시작함 (fallback)
+        let cache = unsafe {
+            device.create_pipeline_cache(&wgpu::PipelineCacheDescriptor {
+                label: Some("Pipeline Cache"),
+                data: data.as_deref(),
+                fallback: true,
+            })
+        };
+
+        Self {
+            cache: Some(cache),
+            path,
+            identity,
+            saved: Mutex::new(data.unwrap_or_default()),
+        }
+    }
+
+    pub fn get(&self) -> Option<&wgpu::PipelineCache> {
+        self.cache.as_ref()
+    }
+
+    // 파이프라인을 만든 직후 호출 (Android 앱은 종료 이벤트 없이 끝날 수 있음)
+    pub fn save(&self) {
+        let (Some(cache), Some(path)) = (&self.cache, &self.path) else {
+            return;
+        };
+        let Some(data) = cache.get_data() else {
+            return;
+        };
+
+        let mut saved = self.saved.lock().unwrap();
+        if *saved == data {
+            return;
+        }
+
+        match write_cache_file(path, &self.identity, &data) {
+            Ok(()) => {
+                log::debug!(
+                    "Pipeline cache saved: {} ({} bytes)",
+                    path.display(),
+                    data.len()
+                );
+                *saved = data;
+            }
+            Err(e) => log::warn!("Failed to save pipeline cache {}: {}", path.display(), e),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use eren_common::gltf_loader::{self, GltfLoadError};
+use thiserror::Error;
+
+use crate::{
+    asset::{self, AssetLoadError, AssetSource},
+    test_vertex_input::mesh::{Mesh, MeshSource, TestScene},
+};
+
+const SHADER_PATH: &str = "shaders/shader.wgsl";
+
+#[derive(Debug, Error)]
+pub enum TestAssetsLoadError {
+    #[error("Failed to load asset: {0}")]
+    LoadAsset(#[from] AssetLoadError),
+
+    #[error("Failed to load glTF mesh: {0}")]
+    LoadGltf(#[from] GltfLoadError<AssetLoadError>),
+}
+
+// 렌더러를 만들기 전에 장면에 필요한 asset을 비동기로 미리 읽어 둠
+pub struct TestAssets {
+    pub shader: String,
+    pub mesh: Mesh,
+    // 텍스처가 없는 장면은 None
+    pub texture: Option<Vec<u8>>,
+}
+
+impl TestAssets {
+    pub async fn load(
+        source: &dyn AssetSource,
+        scene: TestScene,
+    ) -> Result<Self, TestAssetsLoadError> {
+        let shader = asset::load_string(source, SHADER_PATH).await?;
+
+        let mesh = match scene.mesh_source() {
+            MeshSource::Builtin { vertices, indices } => Mesh::from_builtin(vertices, indices),
+            MeshSource::Gltf(path) => Mesh::from_gltf(gltf_loader::load_gltf(source, path).await?),
+        };
+
+        let texture = match scene.texture_asset() {
+            Some(path) => Some(source.load(path).await?),
+            None => None,
+        };
+
+        Ok(Self {
+            shader,
+            mesh,
+            texture,
+        })
+    }
+}
@@ -1,14 +1,18 @@
-use eren_render_shared::device::Device;
-
-use crate::test_vertex_input::{
-    ubo::UniformBufferObject,
-    vertex::{VERTEX_DESC, Vertex},
+use eren_common::time_source::{SystemTimeSource, TimeSource};
+use eren_vertex::WgpuVertexLayout;
+use thiserror::Error;
+use winit::dpi::PhysicalSize;
+
+use crate::{
+    depth::{DEPTH_FORMAT, DepthTexture},
+    msaa::MultisampleTexture,
+    pipeline_cache::PipelineCache,
+    test_vertex_input::{
+        assets::TestAssets, mesh::MeshIndices, ubo::UniformBufferObject, vertex::Vertex,
+    },
+    texture::{Texture, TextureCreationError},
+    typed_buffer::{IndexBuffer, TypedBuffer},
 };
-use glam::{Vec2, Vec3};
-
-use chrono::Utc;
-
-const SHADER_STR: &str = include_str!("./shaders/shader.wgsl");
 
 const CLEAR_COLOR: wgpu::Color = wgpu::Color {
     r: 0.1921,
@@ -17,69 +21,43 @@ const CLEAR_COLOR: wgpu::Color = wgpu::Color {
     a: 1.0,
 };
 
-const TEST_VERTICES: [Vertex; 4] = [
-    Vertex {
-        pos: Vec2::new(-0.5, -0.5),
-        color: Vec3::new(1.0, 0.0, 0.0),
-    },
-    Vertex {
-        pos: Vec2::new(0.5, -0.5),
-        color: Vec3::new(0.0, 1.0, 0.0),
-    },
-    Vertex {
-        pos: Vec2::new(0.5, 0.5),
-        color: Vec3::new(0.0, 0.0, 1.0),
-    },
-    Vertex {
-        pos: Vec2::new(-0.5, 0.5),
-        color: Vec3::new(1.0, 1.0, 1.0),
-    },
-];
-
-fn create_vertex_buffer(device: &Device) -> wgpu::Buffer {
-    let vertex_size = (std::mem::size_of::<Vertex>() * TEST_VERTICES.len()) as wgpu::BufferAddress;
-
-    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
-        label: Some("Test Buffer"),
-        size: vertex_size,
-        usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
-        mapped_at_creation: false,
-    });
-
-    let vertex_bytes = unsafe {
-        std::slice::from_raw_parts(
-            TEST_VERTICES.as_ptr() as *const u8,
-            TEST_VERTICES.len() * std::mem::size_of::<Vertex>(),
-        )
-    };
-
-    device.queue.write_buffer(&buffer, 0, vertex_bytes);
-
-    buffer
+fn create_vertex_buffer(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    vertices: &[Vertex],
+) -> TypedBuffer<Vertex> {
+    TypedBuffer::with_data(
+        device,
+        queue,
+        "Test Buffer",
+        wgpu::BufferUsages::VERTEX,
+        vertices,
+    )
 }
 
-const TEST_INDICES: [u16; 6] = [0, 1, 2, 2, 3, 0];
-
-fn create_index_buffer(device: &Device) -> wgpu::Buffer {
-    let index_size = (std::mem::size_of::<u16>() * TEST_INDICES.len()) as wgpu::BufferAddress;
-
-    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
-        label: Some("Test Buffer"),
-        size: index_size,
-        usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
-        mapped_at_creation: false,
-    });
-
-    let index_bytes = unsafe {
-        std::slice::from_raw_parts(
-            TEST_INDICES.as_ptr() as *const u8,
-            TEST_INDICES.len() * std::mem::size_of::<u16>(),
-        )
-    };
-
-    device.queue.write_buffer(&buffer, 0, index_bytes);
-
-    buffer
+fn create_index_buffer(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    indices: &MeshIndices,
+) -> IndexBuffer {
+    let usage = wgpu::BufferUsages::INDEX;
+
+    match indices {
+        MeshIndices::U16(indices) => IndexBuffer::U16(TypedBuffer::with_data(
+            device,
+            queue,
+            "Test Buffer",
+            usage,
+            indices,
+        )),
+        MeshIndices::U32(indices) => IndexBuffer::U32(TypedBuffer::with_data(
+            device,
+            queue,
+            "Test Buffer",
+            usage,
+            indices,
+        )),
+    }
 }
 
 // WebGL에서는 하나의 WebGLBuffer를 gl.ARRAY_BUFFER와 gl.ELEMENT_ARRAY_BUFFER에 동시에 사용할 수 없습니다.
@@ -106,19 +84,8 @@ fn create_combined_buffer(device: &Device) -> CombinedBuffer {
         mapped_at_creation: false,
     });
 
-    let vertex_bytes = unsafe {
-        std::slice::from_raw_parts(
-            TEST_VERTICES.as_ptr() as *const u8,
-            TEST_VERTICES.len() * std::mem::size_of::<Vertex>(),
-        )
-    };
-
-    let index_bytes = unsafe {
-        std::slice::from_raw_parts(
-            TEST_INDICES.as_ptr() as *const u8,
-            TEST_INDICES.len() * std::mem::size_of::<u16>(),
-        )
-    };
+    let vertex_bytes: &[u8] = bytemuck::cast_slice(TEST_VERTICES);
+    let index_bytes: &[u8] = bytemuck::cast_slice(TEST_INDICES);
 
     device.queue.write_buffer(&buffer, 0, vertex_bytes);
     device
@@ -133,34 +100,69 @@ fn create_combined_buffer(device: &Device) -> CombinedBuffer {
     }
 }*/
 
+#[derive(Debug, Error)]
+pub enum TestRenderPassInitializationError {
+    #[error("Failed to create texture: {0}")]
+    CreateTexture(#[from] TextureCreationError),
+}
+
+fn create_scene_texture(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    assets: &TestAssets,
+) -> Result<Texture, TestRenderPassInitializationError> {
+    let Some(bytes) = assets.texture.as_deref() else {
+        return Ok(Texture::white(device, queue));
+    };
+
+    Ok(Texture::from_image_bytes(
+        device,
+        queue,
+        bytes,
+        "Scene Texture",
+    )?)
+}
+
 pub struct TestRenderPass {
     pipeline: wgpu::RenderPipeline,
-    vertex_buffer: wgpu::Buffer,
-    index_buffer: wgpu::Buffer,
-    index_count: u32,
-    ubo_buffer: wgpu::Buffer,
-    ubo_bind_group: wgpu::BindGroup,
-    start_time: chrono::DateTime<chrono::Utc>,
+    vertex_buffer: TypedBuffer<Vertex>,
+    index_buffer: IndexBuffer,
+    ubo_buffer: TypedBuffer<UniformBufferObject>,
+    // bind group이 참조하므로 render pass와 함께 유지
+    _texture: Texture,
+    bind_group: wgpu::BindGroup,
+    depth_texture: DepthTexture,
+    // sample_count가 1이면 대상 텍스처에 직접 렌더링
+    sample_count: u32,
+    format: wgpu::TextureFormat,
+    multisample_texture: Option<MultisampleTexture>,
+    time_source: Box<dyn TimeSource>,
 }
 
 impl TestRenderPass {
-    pub fn new(device: &Device) -> Self {
+    pub fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        format: wgpu::TextureFormat,
+        assets: &TestAssets,
+        size: PhysicalSize<u32>,
+        sample_count: u32,
+        pipeline_cache: &PipelineCache,
+    ) -> Result<Self, TestRenderPassInitializationError> {
         let shader_module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Test Shader"),
-            source: wgpu::ShaderSource::Wgsl(SHADER_STR.into()),
+            source: wgpu::ShaderSource::Wgsl(assets.shader.as_str().into()),
         });
 
-        let ubo_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("UBO Buffer"),
-            size: std::mem::size_of::<UniformBufferObject>() as wgpu::BufferAddress,
-            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
-            mapped_at_creation: false,
-        });
+        let ubo_buffer = TypedBuffer::new(device, "UBO Buffer", wgpu::BufferUsages::UNIFORM, 1);
 
-        let ubo_bind_group_layout =
-            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
-                label: Some("UBO Bind Group Layout"),
-                entries: &[wgpu::BindGroupLayoutEntry {
+        let texture = create_scene_texture(device, queue, assets)?;
+
+        // Vulkan 크레이트의 descriptor set과 같은 구성 (셰이더를 SPIR-V로 변환해 함께 사용)
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Test Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
                     binding: 0,
                     visibility: wgpu::ShaderStages::VERTEX,
                     ty: wgpu::BindingType::Buffer {
@@ -169,21 +171,48 @@ impl TestRenderPass {
                         min_binding_size: None,
                     },
                     count: None,
-                }],
-            });
-
-        let ubo_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("UBO Bind Group"),
-            layout: &ubo_bind_group_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: ubo_buffer.as_entire_binding(),
-            }],
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Test Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: ubo_buffer.buffer().as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(texture.view()),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(texture.sampler()),
+                },
+            ],
         });
 
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Test Pipeline Layout"),
-            bind_group_layouts: &[&ubo_bind_group_layout],
+            bind_group_layouts: &[&bind_group_layout],
             push_constant_ranges: &[],
         });
 
@@ -193,53 +222,89 @@ impl TestRenderPass {
             vertex: wgpu::VertexState {
                 module: &shader_module,
                 entry_point: Some("vs_main"),
-                buffers: &[VERTEX_DESC],
+                buffers: &[Vertex::LAYOUT],
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
             },
             fragment: Some(wgpu::FragmentState {
                 module: &shader_module,
                 entry_point: Some("fs_main"),
                 targets: &[Some(wgpu::ColorTargetState {
-                    format: device.surface_config.format,
+                    format,
                     blend: Some(wgpu::BlendState::ALPHA_BLENDING),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
             }),
             primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleStrip,
-                strip_index_format: Some(wgpu::IndexFormat::Uint16),
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: DEPTH_FORMAT,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
                 ..Default::default()
             },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState::default(),
             multiview: None,
-            cache: None,
+            cache: pipeline_cache.get(),
         });
+        pipeline_cache.save();
 
-        let vertex_buffer = create_vertex_buffer(&device);
-        let index_buffer = create_index_buffer(&device);
+        let vertex_buffer = create_vertex_buffer(device, queue, &assets.mesh.vertices);
+        let index_buffer = create_index_buffer(device, queue, &assets.mesh.indices);
+        let depth_texture = DepthTexture::new(device, size.width, size.height, sample_count);
+        let multisample_texture = (sample_count > 1).then(|| {
+            MultisampleTexture::new(device, format, size.width, size.height, sample_count)
+        });
 
-        Self {
+        Ok(Self {
             pipeline,
             vertex_buffer,
             index_buffer,
-            index_count: TEST_INDICES.len() as u32,
             ubo_buffer,
-            ubo_bind_group,
-            start_time: Utc::now(),
+            _texture: texture,
+            bind_group,
+            depth_texture,
+            sample_count,
+            format,
+            multisample_texture,
+            time_source: Box::new(SystemTimeSource::new()),
+        })
+    }
+
+    pub fn set_time_source(&mut self, time_source: Box<dyn TimeSource>) {
+        self.time_source = time_source;
+    }
+
+    // 렌더 대상 크기가 바뀌면 깊이 버퍼와 멀티샘플 버퍼도 같은 크기로 다시 만듦
+    pub fn resize(&mut self, device: &wgpu::Device, width: u32, height: u32) {
+        if !self.depth_texture.matches(width, height) {
+            self.depth_texture = DepthTexture::new(device, width, height, self.sample_count);
+        }
+
+        if let Some(multisample_texture) = self.multisample_texture.as_mut()
+            && !multisample_texture.matches(width, height)
+        {
+            *multisample_texture =
+                MultisampleTexture::new(device, self.format, width, height, self.sample_count);
         }
     }
 
-    fn update_uniform_buffer(&mut self, device: &Device, window_width: u32, window_height: u32) {
-        let time = self
-            .start_time
-            .signed_duration_since(Utc::now())
-            .num_milliseconds() as f32
-            / 1000.0;
+    fn update_uniform_buffer(
+        &mut self,
+        queue: &wgpu::Queue,
+        window_width: u32,
+        window_height: u32,
+    ) {
+        let time = self.time_source.elapsed_secs();
 
         // 모델 행렬: Z축 회전
-        let model = glam::Mat4::from_rotation_z(time.to_radians() * 90.0);
+        let model = glam::Mat4::from_rotation_z(-time.to_radians() * 90.0);
 
         // 뷰 행렬: 카메라 위치 설정
         let eye = glam::Vec3::new(2.0, 2.0, 2.0);
@@ -253,48 +318,61 @@ impl TestRenderPass {
 
         let ubo = UniformBufferObject { model, view, proj };
 
-        let ubo_bytes = unsafe {
-            std::slice::from_raw_parts(
-                &ubo as *const UniformBufferObject as *const u8,
-                std::mem::size_of::<UniformBufferObject>(),
-            )
-        };
-
         // 메모리에 데이터 복사
-        device.queue.write_buffer(&self.ubo_buffer, 0, ubo_bytes);
+        self.ubo_buffer.write(queue, 0, &[ubo]);
     }
 
     pub fn record_commands(
         &mut self,
-        device: &Device,
+        queue: &wgpu::Queue,
         view: &wgpu::TextureView,
         encoder: &mut wgpu::CommandEncoder,
         window_width: u32,
         window_height: u32,
     ) {
-        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("Test Render Pass"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+        // MSAA 사용 시 멀티샘플 버퍼에 렌더링한 뒤 view로 resolve하고 멀티샘플 버퍼는 버림
+        let color_attachment = match self.multisample_texture.as_ref() {
+            Some(multisample_texture) => wgpu::RenderPassColorAttachment {
+                view: multisample_texture.view(),
+                resolve_target: Some(view),
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(CLEAR_COLOR),
+                    store: wgpu::StoreOp::Discard,
+                },
+            },
+            None => wgpu::RenderPassColorAttachment {
                 view,
                 resolve_target: None,
                 ops: wgpu::Operations {
                     load: wgpu::LoadOp::Clear(CLEAR_COLOR),
                     store: wgpu::StoreOp::Store,
                 },
-            })],
-            depth_stencil_attachment: None,
+            },
+        };
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Test Render Pass"),
+            color_attachments: &[Some(color_attachment)],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: self.depth_texture.view(),
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0),
+                    store: wgpu::StoreOp::Discard,
+                }),
+                stencil_ops: None,
+            }),
             timestamp_writes: None,
             occlusion_query_set: None,
         });
 
         render_pass.set_pipeline(&self.pipeline);
 
-        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(0..));
-        render_pass.set_index_buffer(self.index_buffer.slice(0..), wgpu::IndexFormat::Uint16);
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.buffer().slice(0..));
+        render_pass.set_index_buffer(self.index_buffer.slice(), self.index_buffer.format());
 
-        self.update_uniform_buffer(device, window_width, window_height);
-        render_pass.set_bind_group(0, &self.ubo_bind_group, &[]);
+        self.update_uniform_buffer(queue, window_width, window_height);
+        render_pass.set_bind_group(0, &self.bind_group, &[]);
 
-        render_pass.draw_indexed(0..self.index_count, 0, 0..1);
+        render_pass.draw_indexed(0..self.index_buffer.len() as u32, 0, 0..1);
     }
 }
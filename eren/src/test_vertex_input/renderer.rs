@@ -1,24 +1,74 @@
+use eren_common::time_source::TimeSource;
 use eren_render_shared::{device::Device, surface::Surface};
+use winit::dpi::PhysicalSize;
 
-use crate::test_vertex_input::render_pass::TestRenderPass;
+use crate::{
+    offscreen::{OffscreenError, OffscreenTarget},
+    pipeline_cache::PipelineCache,
+    test_vertex_input::{
+        assets::TestAssets,
+        render_pass::{TestRenderPass, TestRenderPassInitializationError},
+    },
+};
 
 pub struct TestRenderer {
     render_pass: TestRenderPass,
 }
 
 impl TestRenderer {
-    pub fn new(device: &Device) -> Self {
-        Self {
-            render_pass: TestRenderPass::new(device),
-        }
+    pub fn new(
+        device: &Device,
+        assets: &TestAssets,
+        sample_count: u32,
+        pipeline_cache: &PipelineCache,
+    ) -> Result<Self, TestRenderPassInitializationError> {
+        Self::with_format(
+            device,
+            &device.queue,
+            device.surface_config.format,
+            assets,
+            PhysicalSize::new(device.surface_config.width, device.surface_config.height),
+            sample_count,
+            pipeline_cache,
+        )
+    }
+
+    pub fn with_format(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        format: wgpu::TextureFormat,
+        assets: &TestAssets,
+        size: PhysicalSize<u32>,
+        sample_count: u32,
+        pipeline_cache: &PipelineCache,
+    ) -> Result<Self, TestRenderPassInitializationError> {
+        Ok(Self {
+            render_pass: TestRenderPass::new(
+                device,
+                queue,
+                format,
+                assets,
+                size,
+                sample_count,
+                pipeline_cache,
+            )?,
+        })
+    }
+
+    // surface 재설정 시 호출
+    pub fn resize(&mut self, device: &wgpu::Device, width: u32, height: u32) {
+        self.render_pass.resize(device, width, height);
+    }
+
+    pub fn set_time_source(&mut self, time_source: Box<dyn TimeSource>) {
+        self.render_pass.set_time_source(time_source);
     }
 
     pub fn render(
         &mut self,
         surface: &Surface,
         device: &Device,
-        window_width: u32,
-        window_height: u32,
+        size: PhysicalSize<u32>,
     ) -> Result<(), wgpu::SurfaceError> {
         let output = surface.get_current_texture()?;
         let view = output
@@ -29,12 +79,43 @@ impl TestRenderer {
             label: Some("Test Render Encoder"),
         });
 
-        self.render_pass
-            .record_commands(device, &view, &mut encoder, window_width, window_height);
+        self.render_pass.record_commands(
+            &device.queue,
+            &view,
+            &mut encoder,
+            size.width,
+            size.height,
+        );
 
         device.queue.submit(std::iter::once(encoder.finish()));
         output.present();
 
         Ok(())
     }
+
+    pub fn render_offscreen(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        target: &OffscreenTarget,
+    ) -> Result<Vec<u8>, OffscreenError> {
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Test Offscreen Encoder"),
+        });
+
+        self.render_pass.resize(device, target.width, target.height);
+
+        self.render_pass.record_commands(
+            queue,
+            target.view(),
+            &mut encoder,
+            target.width,
+            target.height,
+        );
+        target.copy_to_readback_buffer(&mut encoder);
+
+        queue.submit(std::iter::once(encoder.finish()));
+
+        target.read_pixels(device)
+    }
 }
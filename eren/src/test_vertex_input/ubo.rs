@@ -1,7 +1,8 @@
+use bytemuck::{Pod, Zeroable};
 use glam::Mat4;
 
 #[repr(C, align(16))]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
 pub struct UniformBufferObject {
     pub model: Mat4,
     pub view: Mat4,
@@ -1,27 +1,12 @@
+use bytemuck::{Pod, Zeroable};
+use eren_vertex::VertexLayout;
 use glam::{Vec2, Vec3};
 
+// location은 필드 순서대로 0(pos), 1(color), 2(tex_coord)
 #[repr(C)]
-#[derive(Clone, Debug, Copy)]
+#[derive(Clone, Debug, Copy, Pod, Zeroable, VertexLayout)]
 pub struct Vertex {
-    pub pos: Vec2,
+    pub pos: Vec3,
     pub color: Vec3,
+    pub tex_coord: Vec2,
 }
-
-pub const VERTEX_DESC: wgpu::VertexBufferLayout<'static> = wgpu::VertexBufferLayout {
-    array_stride: std::mem::size_of::<Vertex>() as wgpu::BufferAddress,
-    step_mode: wgpu::VertexStepMode::Vertex,
-    attributes: &[
-        // location(0) - Vec2 (pos)
-        wgpu::VertexAttribute {
-            offset: 0,
-            shader_location: 0,
-            format: wgpu::VertexFormat::Float32x2,
-        },
-        // location(1) - Vec3 (color)
-        wgpu::VertexAttribute {
-            offset: std::mem::size_of::<Vec2>() as wgpu::BufferAddress,
-            shader_location: 1,
-            format: wgpu::VertexFormat::Float32x3,
-        },
-    ],
-};
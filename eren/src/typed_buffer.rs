@@ -0,0 +1,170 @@
+use std::marker::PhantomData;
+
+use bytemuck::Pod;
+
+// 원소 타입이 고정된 GPU 버퍼, T: Pod이므로 패딩이나 포인터가 있는 타입은 컴파일 에러
+pub struct TypedBuffer<T: Pod> {
+    buffer: wgpu::Buffer,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Pod> TypedBuffer<T> {
+    // 원소 len개 크기의 빈 버퍼, COPY_DST는 항상 포함
+    // write_buffer는 4바이트 단위로만 쓸 수 있으므로 크기를 COPY_BUFFER_ALIGNMENT로 올림
+    // (예: u16 인덱스 3개는 6바이트가 아닌 8바이트)
+    pub fn new(device: &wgpu::Device, label: &str, usage: wgpu::BufferUsages, len: usize) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size: wgpu::util::align_to(
+                std::mem::size_of::<T>() as wgpu::BufferAddress * len as wgpu::BufferAddress,
+                wgpu::COPY_BUFFER_ALIGNMENT,
+            ),
+            usage: usage | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self {
+            buffer,
+            len,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn with_data(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        label: &str,
+        usage: wgpu::BufferUsages,
+        data: &[T],
+    ) -> Self {
+        let buffer = Self::new(device, label, usage, data.len());
+        buffer.write(queue, 0, data);
+
+        buffer
+    }
+
+    // index는 원소 단위 위치, 시작 위치는 4바이트 정렬이어야 함
+    // 버퍼 끝까지 쓰는 경우에만 크기가 4바이트 단위가 아니어도 되며, 남는 부분은 0으로 채움
+    pub fn write(&self, queue: &wgpu::Queue, index: usize, data: &[T]) {
+        assert!(
+            index + data.len() <= self.len,
+            "write of {} elements at {} overflows buffer of {} elements",
+            data.len(),
+            index,
+            self.len
+        );
+
+        let offset = (std::mem::size_of::<T>() * index) as wgpu::BufferAddress;
+        let bytes: &[u8] = bytemuck::cast_slice(data);
+
+        assert!(
+            offset.is_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT),
+            "write at element {} is not {}-byte aligned",
+            index,
+            wgpu::COPY_BUFFER_ALIGNMENT
+        );
+
+        let padded_len = wgpu::util::align_to(
+            bytes.len() as wgpu::BufferAddress,
+            wgpu::COPY_BUFFER_ALIGNMENT,
+        );
+        if padded_len == bytes.len() as wgpu::BufferAddress {
+            queue.write_buffer(&self.buffer, offset, bytes);
+            return;
+        }
+
+        // 중간 원소를 0으로 덮어쓰지 않도록 버퍼 끝까지 쓰는 경우만 허용
+        assert!(
+            index + data.len() == self.len,
+            "write of {} bytes at element {} is not {}-byte aligned",
+            bytes.len(),
+            index,
+            wgpu::COPY_BUFFER_ALIGNMENT
+        );
+
+        let mut padded = bytes.to_vec();
+        padded.resize(padded_len as usize, 0);
+        queue.write_buffer(&self.buffer, offset, &padded);
+    }
+
+    pub fn buffer(&self) -> &wgpu::Buffer {
+        &self.buffer
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+}
+
+// 메시 인덱스 크기에 따라 16/32비트 인덱스 버퍼를 선택
+pub enum IndexBuffer {
+    U16(TypedBuffer<u16>),
+    U32(TypedBuffer<u32>),
+}
+
+impl IndexBuffer {
+    pub fn slice(&self) -> wgpu::BufferSlice<'_> {
+        match self {
+            IndexBuffer::U16(buffer) => buffer.buffer().slice(..),
+            IndexBuffer::U32(buffer) => buffer.buffer().slice(..),
+        }
+    }
+
+    pub fn format(&self) -> wgpu::IndexFormat {
+        match self {
+            IndexBuffer::U16(_) => wgpu::IndexFormat::Uint16,
+            IndexBuffer::U32(_) => wgpu::IndexFormat::Uint32,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            IndexBuffer::U16(buffer) => buffer.len(),
+            IndexBuffer::U32(buffer) => buffer.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::offscreen::HeadlessContext;
+
+    // glTF의 삼각형 하나처럼 u16 인덱스 수가 홀수인 경우 (6바이트)
+    #[test]
+    fn odd_u16_index_count() {
+        let context = pollster::block_on(HeadlessContext::new()).unwrap();
+        let indices: [u16; 3] = [2, 1, 0];
+
+        let buffer = TypedBuffer::with_data(
+            &context.device,
+            &context.queue,
+            "Test Index Buffer",
+            wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_SRC,
+            &indices,
+        );
+        assert_eq!(buffer.buffer().size(), 8);
+        assert_eq!(buffer.len(), 3);
+
+        let readback = context.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Test Readback Buffer"),
+            size: 8,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = context
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_buffer_to_buffer(buffer.buffer(), 0, &readback, 0, 8);
+        context.queue.submit([encoder.finish()]);
+
+        let slice = readback.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |result| result.unwrap());
+        context.device.poll(wgpu::PollType::Wait).unwrap();
+
+        let data = slice.get_mapped_range();
+        assert_eq!(bytemuck::cast_slice::<u8, u16>(&data), [2, 1, 0, 0]);
+    }
+}
@@ -0,0 +1,7 @@
+// wgpu adapter가 없으면 GL/GLES로 fallback하므로 소프트웨어 렌더러(llvmpipe 등)에서도 실행됨
+#[test]
+fn golden_images_match() {
+    if let Err(e) = eren_mobile_test::run_golden_tests(false) {
+        panic!("Golden image test failed: {}", e);
+    }
+}
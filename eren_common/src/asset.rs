@@ -0,0 +1,281 @@
+use std::{future::Future, pin::Pin};
+
+use thiserror::Error;
+
+use crate::gltf_loader::GltfSource;
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+use std::collections::HashMap;
+
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+use std::path::PathBuf;
+
+#[cfg(target_os = "android")]
+use std::sync::OnceLock;
+
+#[cfg(target_os = "android")]
+use winit::platform::android::activity::AndroidApp;
+
+// 데스크톱에서 asset 디렉터리를 바꿀 때 사용 (예: EREN_ASSET_DIR=/path/to/assets)
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+pub const ASSET_DIR_ENV: &str = "EREN_ASSET_DIR";
+
+// (asset 디렉터리 기준 상대 경로, 내용), 각 크레이트가 include_bytes!로 만듦
+pub type EmbeddedAsset = (&'static str, &'static [u8]);
+
+#[derive(Debug, Error)]
+pub enum AssetLoadError {
+    #[error("Asset not found: {0}")]
+    NotFound(String),
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[error("Failed to read asset {0}: {1}")]
+    Read(String, std::io::Error),
+
+    #[error("Failed to decode asset {0} as UTF-8: {1}")]
+    DecodeUtf8(String, std::string::FromUtf8Error),
+
+    #[cfg(target_os = "ios")]
+    #[error("Failed to locate app bundle: {0}")]
+    LocateBundle(std::io::Error),
+
+    #[cfg(target_os = "android")]
+    #[error("Android app is not set")]
+    MissingAndroidApp,
+}
+
+pub type AssetFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Vec<u8>, AssetLoadError>> + Send + 'a>>;
+
+// path는 asset 디렉터리 기준 상대 경로 (예: models/sphere.gltf)
+pub trait AssetSource: Send + Sync {
+    fn load<'a>(&'a self, path: &'a str) -> AssetFuture<'a>;
+}
+
+// glTF 로더가 외부 버퍼를 같은 source에서 읽을 수 있도록 연결
+impl GltfSource for dyn AssetSource + '_ {
+    type Error = AssetLoadError;
+
+    fn load<'a>(
+        &'a self,
+        path: &'a str,
+    ) -> impl Future<Output = Result<Vec<u8>, AssetLoadError>> + 'a {
+        AssetSource::load(self, path)
+    }
+}
+
+pub async fn load_string(source: &dyn AssetSource, path: &str) -> Result<String, AssetLoadError> {
+    let bytes = source.load(path).await?;
+
+    String::from_utf8(bytes).map_err(|e| AssetLoadError::DecodeUtf8(path.to_string(), e))
+}
+
+// 데스크톱 파일 시스템 또는 iOS 번들 안의 디렉터리에서 asset을 읽음
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+pub struct FileAssetSource {
+    root: PathBuf,
+}
+
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+impl FileAssetSource {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    // 환경 변수가 없으면 크레이트가 넘겨준 기본 디렉터리를 사용
+    #[cfg(not(target_os = "ios"))]
+    pub fn from_env(default_root: impl Into<PathBuf>) -> Self {
+        match std::env::var(ASSET_DIR_ENV) {
+            Ok(root) => Self::new(root),
+            Err(_) => Self::new(default_root),
+        }
+    }
+
+    // iOS 앱 번들은 실행 파일과 같은 디렉터리에 리소스를 둠
+    #[cfg(target_os = "ios")]
+    pub fn bundle() -> Result<Self, AssetLoadError> {
+        let executable = std::env::current_exe().map_err(AssetLoadError::LocateBundle)?;
+        let bundle_dir = executable.parent().ok_or_else(|| {
+            AssetLoadError::LocateBundle(std::io::Error::from(std::io::ErrorKind::NotFound))
+        })?;
+
+        Ok(Self::new(bundle_dir.join("assets")))
+    }
+}
+
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+impl AssetSource for FileAssetSource {
+    fn load<'a>(&'a self, path: &'a str) -> AssetFuture<'a> {
+        Box::pin(async move {
+            std::fs::read(self.root.join(path)).map_err(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => AssetLoadError::NotFound(path.to_string()),
+                _ => AssetLoadError::Read(path.to_string(), e),
+            })
+        })
+    }
+}
+
+// AssetManager는 APK 안의 assets/ 디렉터리를 읽음
+#[cfg(target_os = "android")]
+static ANDROID_APP: OnceLock<AndroidApp> = OnceLock::new();
+
+// android_main에서 이벤트 루프를 시작하기 전에 호출
+#[cfg(target_os = "android")]
+pub fn set_android_app(app: AndroidApp) {
+    let _ = ANDROID_APP.set(app);
+}
+
+// 앱 데이터 디렉터리 등 asset 외의 경로를 찾을 때 사용
+#[cfg(target_os = "android")]
+pub fn android_app() -> Option<&'static AndroidApp> {
+    ANDROID_APP.get()
+}
+
+#[cfg(target_os = "android")]
+pub struct AndroidAssetSource {
+    app: AndroidApp,
+}
+
+#[cfg(target_os = "android")]
+impl AndroidAssetSource {
+    pub fn new(app: AndroidApp) -> Self {
+        Self { app }
+    }
+}
+
+#[cfg(target_os = "android")]
+impl AssetSource for AndroidAssetSource {
+    fn load<'a>(&'a self, path: &'a str) -> AssetFuture<'a> {
+        Box::pin(async move {
+            use std::io::Read;
+
+            let file_name = std::ffi::CString::new(path)
+                .map_err(|_| AssetLoadError::NotFound(path.to_string()))?;
+            let mut asset = self
+                .app
+                .asset_manager()
+                .open(&file_name)
+                .ok_or_else(|| AssetLoadError::NotFound(path.to_string()))?;
+
+            let mut bytes = Vec::new();
+            asset
+                .read_to_end(&mut bytes)
+                .map_err(|e| AssetLoadError::Read(path.to_string(), e))?;
+
+            Ok(bytes)
+        })
+    }
+}
+
+// 메모리에 올려 둔 asset, 테스트나 파일 시스템이 없는 환경에서 사용
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub struct MemoryAssetSource {
+    assets: HashMap<String, Vec<u8>>,
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+impl MemoryAssetSource {
+    pub fn new() -> Self {
+        Self {
+            assets: HashMap::new(),
+        }
+    }
+
+    // 바이너리에 포함된 asset으로 채움
+    pub fn embedded(assets: &[EmbeddedAsset]) -> Self {
+        let mut source = Self::new();
+
+        for &(path, bytes) in assets {
+            source.insert(path, bytes.to_vec());
+        }
+
+        source
+    }
+
+    pub fn insert(&mut self, path: impl Into<String>, bytes: Vec<u8>) {
+        self.assets.insert(path.into(), bytes);
+    }
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+impl Default for MemoryAssetSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+impl AssetSource for MemoryAssetSource {
+    fn load<'a>(&'a self, path: &'a str) -> AssetFuture<'a> {
+        Box::pin(async move {
+            self.assets
+                .get(path)
+                .cloned()
+                .ok_or_else(|| AssetLoadError::NotFound(path.to_string()))
+        })
+    }
+}
+
+// 플랫폼별 기본 asset source
+// 데스크톱과 wasm은 크레이트마다 asset 위치가 다르므로 호출하는 크레이트가 넘겨줌
+#[cfg(target_os = "android")]
+pub fn default_asset_source() -> Result<Box<dyn AssetSource>, AssetLoadError> {
+    let app = ANDROID_APP
+        .get()
+        .cloned()
+        .ok_or(AssetLoadError::MissingAndroidApp)?;
+
+    Ok(Box::new(AndroidAssetSource::new(app)))
+}
+
+#[cfg(target_os = "ios")]
+pub fn default_asset_source() -> Result<Box<dyn AssetSource>, AssetLoadError> {
+    Ok(Box::new(FileAssetSource::bundle()?))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn default_asset_source(
+    embedded: &[EmbeddedAsset],
+) -> Result<Box<dyn AssetSource>, AssetLoadError> {
+    Ok(Box::new(MemoryAssetSource::embedded(embedded)))
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+pub fn default_asset_source(default_dir: &str) -> Result<Box<dyn AssetSource>, AssetLoadError> {
+    Ok(Box::new(FileAssetSource::from_env(default_dir)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_file_is_not_found() {
+        let source = FileAssetSource::new(
+            std::env::temp_dir().join(format!("eren_common_asset_test_{}", std::process::id())),
+        );
+
+        assert!(matches!(
+            pollster::block_on(AssetSource::load(&source, "missing.png")),
+            Err(AssetLoadError::NotFound(path)) if path == "missing.png"
+        ));
+    }
+
+    #[test]
+    fn memory_asset_hit_and_miss() {
+        let source = MemoryAssetSource::embedded(&[("shaders/shader.wgsl", b"@vertex")]);
+
+        assert_eq!(
+            pollster::block_on(AssetSource::load(&source, "shaders/shader.wgsl")).unwrap(),
+            b"@vertex"
+        );
+        assert_eq!(
+            pollster::block_on(load_string(&source, "shaders/shader.wgsl")).unwrap(),
+            "@vertex"
+        );
+        assert!(matches!(
+            pollster::block_on(AssetSource::load(&source, "shaders/missing.wgsl")),
+            Err(AssetLoadError::NotFound(path)) if path == "shaders/missing.wgsl"
+        ));
+    }
+}
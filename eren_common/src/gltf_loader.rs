@@ -0,0 +1,360 @@
+use std::future::Future;
+
+use base64::Engine;
+use glam::{Mat3, Mat4, Vec2, Vec3};
+use thiserror::Error;
+
+// 각 크레이트의 asset source를 glTF 로더에 연결
+// path는 asset 디렉터리 기준 상대 경로
+pub trait GltfSource {
+    type Error: std::error::Error + 'static;
+
+    fn load<'a>(&'a self, path: &'a str)
+    -> impl Future<Output = Result<Vec<u8>, Self::Error>> + 'a;
+}
+
+#[derive(Debug, Error)]
+pub enum GltfLoadError<E: std::error::Error + 'static> {
+    #[error("Failed to load asset: {0}")]
+    LoadAsset(#[source] E),
+
+    #[error("Failed to parse glTF: {0}")]
+    Parse(#[from] gltf::Error),
+
+    #[error("Failed to decode data URI: {0}")]
+    DecodeDataUri(#[from] base64::DecodeError),
+
+    #[error("Unsupported buffer URI: {0}")]
+    UnsupportedUri(String),
+
+    #[error("GLB binary chunk is missing")]
+    MissingBinaryChunk,
+
+    #[error("Buffer {index} is too short: {actual} bytes, expected {expected}")]
+    BufferTooShort {
+        index: usize,
+        actual: usize,
+        expected: usize,
+    },
+
+    #[error("glTF has no scene")]
+    MissingScene,
+
+    #[error("Primitive has no positions")]
+    MissingPositions,
+
+    #[error("Unsupported primitive mode: {0:?}")]
+    UnsupportedMode(gltf::mesh::Mode),
+
+    #[error("Index {index} is out of range for {vertex_count} vertices")]
+    IndexOutOfRange { index: u32, vertex_count: usize },
+}
+
+// 장면의 모든 삼각형 primitive를 하나로 합친 결과, 노드 변환이 적용된 상태
+// normal이나 UV가 없는 primitive는 0으로 채움
+#[derive(Default)]
+pub struct MeshData {
+    pub positions: Vec<Vec3>,
+    pub normals: Vec<Vec3>,
+    pub tex_coords: Vec<Vec2>,
+    pub indices: Vec<u32>,
+}
+
+// path는 .gltf(JSON) 또는 .glb 파일의 asset 경로
+// 외부 버퍼는 같은 source에서 glTF 파일 기준 상대 경로로 읽음
+pub async fn load_gltf<S: GltfSource + ?Sized>(
+    source: &S,
+    path: &str,
+) -> Result<MeshData, GltfLoadError<S::Error>> {
+    let bytes = source.load(path).await.map_err(GltfLoadError::LoadAsset)?;
+    let gltf::Gltf { document, blob } = gltf::Gltf::from_slice(&bytes)?;
+
+    let buffers = load_buffers(source, path, &document, blob).await?;
+
+    let scene = document
+        .default_scene()
+        .or_else(|| document.scenes().next())
+        .ok_or(GltfLoadError::MissingScene)?;
+
+    let mut mesh = MeshData::default();
+
+    for node in scene.nodes() {
+        append_node(&mut mesh, &buffers, &node, Mat4::IDENTITY)?;
+    }
+
+    log::debug!(
+        "glTF loaded: {} ({} vertices, {} indices)",
+        path,
+        mesh.positions.len(),
+        mesh.indices.len()
+    );
+
+    Ok(mesh)
+}
+
+async fn load_buffers<S: GltfSource + ?Sized>(
+    source: &S,
+    path: &str,
+    document: &gltf::Document,
+    mut blob: Option<Vec<u8>>,
+) -> Result<Vec<Vec<u8>>, GltfLoadError<S::Error>> {
+    let mut buffers = Vec::new();
+
+    for buffer in document.buffers() {
+        let data = match buffer.source() {
+            gltf::buffer::Source::Bin => blob.take().ok_or(GltfLoadError::MissingBinaryChunk)?,
+            gltf::buffer::Source::Uri(uri) => load_buffer_uri(source, path, uri).await?,
+        };
+
+        // GLB 바이너리 청크는 4바이트 정렬을 위해 더 길 수 있음
+        if data.len() < buffer.length() {
+            return Err(GltfLoadError::BufferTooShort {
+                index: buffer.index(),
+                actual: data.len(),
+                expected: buffer.length(),
+            });
+        }
+
+        buffers.push(data);
+    }
+
+    Ok(buffers)
+}
+
+async fn load_buffer_uri<S: GltfSource + ?Sized>(
+    source: &S,
+    gltf_path: &str,
+    uri: &str,
+) -> Result<Vec<u8>, GltfLoadError<S::Error>> {
+    if let Some(data) = uri.strip_prefix("data:") {
+        let (_, encoded) = data
+            .split_once(";base64,")
+            .ok_or_else(|| GltfLoadError::UnsupportedUri(uri.to_string()))?;
+
+        return Ok(base64::engine::general_purpose::STANDARD.decode(encoded)?);
+    }
+
+    let buffer_path = match gltf_path.rsplit_once('/') {
+        Some((dir, _)) => format!("{}/{}", dir, uri),
+        None => uri.to_string(),
+    };
+
+    source
+        .load(&buffer_path)
+        .await
+        .map_err(GltfLoadError::LoadAsset)
+}
+
+fn append_node<E: std::error::Error + 'static>(
+    mesh: &mut MeshData,
+    buffers: &[Vec<u8>],
+    node: &gltf::Node,
+    parent_transform: Mat4,
+) -> Result<(), GltfLoadError<E>> {
+    let transform = parent_transform * Mat4::from_cols_array_2d(&node.transform().matrix());
+
+    if let Some(node_mesh) = node.mesh() {
+        for primitive in node_mesh.primitives() {
+            append_primitive(mesh, buffers, &primitive, transform)?;
+        }
+    }
+
+    for child in node.children() {
+        append_node(mesh, buffers, &child, transform)?;
+    }
+
+    Ok(())
+}
+
+fn append_primitive<E: std::error::Error + 'static>(
+    mesh: &mut MeshData,
+    buffers: &[Vec<u8>],
+    primitive: &gltf::Primitive,
+    transform: Mat4,
+) -> Result<(), GltfLoadError<E>> {
+    if primitive.mode() != gltf::mesh::Mode::Triangles {
+        return Err(GltfLoadError::UnsupportedMode(primitive.mode()));
+    }
+
+    let reader = primitive.reader(|buffer| buffers.get(buffer.index()).map(Vec::as_slice));
+
+    let positions: Vec<Vec3> = reader
+        .read_positions()
+        .ok_or(GltfLoadError::MissingPositions)?
+        .map(Vec3::from)
+        .collect();
+    let vertex_count = positions.len();
+
+    let normals: Vec<Vec3> = match reader.read_normals() {
+        Some(normals) => normals.map(Vec3::from).collect(),
+        None => vec![Vec3::ZERO; vertex_count],
+    };
+
+    let tex_coords: Vec<Vec2> = match reader.read_tex_coords(0) {
+        Some(tex_coords) => tex_coords.into_f32().map(Vec2::from).collect(),
+        None => vec![Vec2::ZERO; vertex_count],
+    };
+
+    // 8/16/32비트 인덱스를 모두 u32로 읽고, 인덱스가 없으면 정점 순서대로 그림
+    let indices: Vec<u32> = match reader.read_indices() {
+        Some(indices) => indices.into_u32().collect(),
+        None => (0..vertex_count as u32).collect(),
+    };
+
+    if let Some(&index) = indices
+        .iter()
+        .find(|&&index| index as usize >= vertex_count)
+    {
+        return Err(GltfLoadError::IndexOutOfRange {
+            index,
+            vertex_count,
+        });
+    }
+
+    let base_vertex = mesh.positions.len() as u32;
+    let normal_matrix = Mat3::from_mat4(transform).inverse().transpose();
+
+    mesh.positions.extend(
+        positions
+            .into_iter()
+            .map(|position| transform.transform_point3(position)),
+    );
+    mesh.normals.extend(
+        normals
+            .into_iter()
+            .map(|normal| (normal_matrix * normal).normalize_or_zero()),
+    );
+    mesh.tex_coords.extend(tex_coords);
+    mesh.indices
+        .extend(indices.into_iter().map(|index| base_vertex + index));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // eren과 eren_vulkan이 함께 사용하는 Android asset 디렉터리
+    struct DirSource;
+
+    impl GltfSource for DirSource {
+        type Error = std::io::Error;
+
+        fn load<'a>(
+            &'a self,
+            path: &'a str,
+        ) -> impl Future<Output = Result<Vec<u8>, Self::Error>> + 'a {
+            let dir = concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../eren/android/app/src/main/assets"
+            );
+            std::future::ready(std::fs::read(std::path::Path::new(dir).join(path)))
+        }
+    }
+
+    // 버퍼를 data URI로 포함한 glTF 하나만 제공
+    struct MemorySource(String);
+
+    impl GltfSource for MemorySource {
+        type Error = std::io::Error;
+
+        fn load<'a>(
+            &'a self,
+            _path: &'a str,
+        ) -> impl Future<Output = Result<Vec<u8>, Self::Error>> + 'a {
+            std::future::ready(Ok(self.0.clone().into_bytes()))
+        }
+    }
+
+    // 삼각형 하나, 인덱스는 component_type(5123: u16, 5125: u32) 형식으로 저장
+    fn triangle_gltf(component_type: u32, indices: &[u32]) -> MemorySource {
+        let mut buffer = Vec::new();
+        for position in [[0.0f32, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]] {
+            for value in position {
+                buffer.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        let positions_len = buffer.len();
+        for &index in indices {
+            match component_type {
+                5123 => buffer.extend_from_slice(&(index as u16).to_le_bytes()),
+                _ => buffer.extend_from_slice(&index.to_le_bytes()),
+            }
+        }
+        let indices_len = buffer.len() - positions_len;
+
+        let json = format!(
+            r#"{{
+                "asset": {{ "version": "2.0" }},
+                "scene": 0,
+                "scenes": [{{ "nodes": [0] }}],
+                "nodes": [{{ "mesh": 0 }}],
+                "meshes": [{{ "primitives": [{{ "attributes": {{ "POSITION": 0 }}, "indices": 1 }}] }}],
+                "buffers": [{{ "byteLength": {}, "uri": "data:application/octet-stream;base64,{}" }}],
+                "bufferViews": [
+                    {{ "buffer": 0, "byteOffset": 0, "byteLength": {} }},
+                    {{ "buffer": 0, "byteOffset": {}, "byteLength": {} }}
+                ],
+                "accessors": [
+                    {{ "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+                       "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 0.0] }},
+                    {{ "bufferView": 1, "componentType": {}, "count": {}, "type": "SCALAR" }}
+                ]
+            }}"#,
+            buffer.len(),
+            base64::engine::general_purpose::STANDARD.encode(&buffer),
+            positions_len,
+            positions_len,
+            indices_len,
+            component_type,
+            indices.len()
+        );
+
+        MemorySource(json)
+    }
+
+    #[test]
+    fn loads_bundled_sphere() {
+        let mesh = pollster::block_on(load_gltf(&DirSource, "models/sphere.gltf")).unwrap();
+
+        // sphere.gltf는 외부 버퍼(sphere.bin)와 32비트 인덱스를 사용
+        assert_eq!(mesh.positions.len(), 561);
+        assert_eq!(mesh.normals.len(), 561);
+        assert_eq!(mesh.tex_coords.len(), 561);
+        assert_eq!(mesh.indices.len(), 2880);
+    }
+
+    #[test]
+    fn reads_16_bit_indices() {
+        let source = triangle_gltf(5123, &[2, 1, 0]);
+        let mesh = pollster::block_on(load_gltf(&source, "triangle.gltf")).unwrap();
+
+        assert_eq!(mesh.positions.len(), 3);
+        assert_eq!(mesh.indices, [2, 1, 0]);
+    }
+
+    #[test]
+    fn reads_32_bit_indices() {
+        let source = triangle_gltf(5125, &[2, 1, 0]);
+        let mesh = pollster::block_on(load_gltf(&source, "triangle.gltf")).unwrap();
+
+        assert_eq!(mesh.positions.len(), 3);
+        assert_eq!(mesh.indices, [2, 1, 0]);
+    }
+
+    #[test]
+    fn rejects_out_of_range_index() {
+        let source = triangle_gltf(5123, &[0, 1, 3]);
+        let result = pollster::block_on(load_gltf(&source, "triangle.gltf"));
+
+        assert!(matches!(
+            result,
+            Err(GltfLoadError::IndexOutOfRange {
+                index: 3,
+                vertex_count: 3
+            })
+        ));
+    }
+}
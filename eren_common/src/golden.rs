@@ -0,0 +1,87 @@
+use image::{Rgba, RgbaImage};
+
+pub struct ImageComparison {
+    pub mismatched_pixels: usize,
+    pub max_channel_diff: u8,
+    pub diff_image: RgbaImage,
+}
+
+// 채널 차이가 tolerance를 넘는 픽셀은 빨간색, 나머지는 기준 이미지를 어둡게 표시
+pub fn compare_images(actual: &RgbaImage, expected: &RgbaImage, tolerance: u8) -> ImageComparison {
+    let mut mismatched_pixels = 0;
+    let mut max_channel_diff = 0;
+    let mut diff_image = RgbaImage::new(expected.width(), expected.height());
+
+    for ((actual_pixel, expected_pixel), diff_pixel) in actual
+        .pixels()
+        .zip(expected.pixels())
+        .zip(diff_image.pixels_mut())
+    {
+        let channel_diff = actual_pixel
+            .0
+            .iter()
+            .zip(expected_pixel.0.iter())
+            .map(|(a, e)| a.abs_diff(*e))
+            .max()
+            .unwrap_or(0);
+
+        max_channel_diff = max_channel_diff.max(channel_diff);
+
+        *diff_pixel = if channel_diff > tolerance {
+            mismatched_pixels += 1;
+            Rgba([255, 0, 0, 255])
+        } else {
+            let [r, g, b, _] = expected_pixel.0;
+            Rgba([r / 4, g / 4, b / 4, 255])
+        };
+    }
+
+    ImageComparison {
+        mismatched_pixels,
+        max_channel_diff,
+        diff_image,
+    }
+}
+
+// 두 백엔드가 같은 이름을 사용하므로 기준 이미지를 서로 비교해 볼 수 있음
+pub fn golden_file_name(scene_name: &str, sample_count: u32, secs: f32) -> String {
+    let millis = (secs * 1000.0).round() as u32;
+
+    if sample_count > 1 {
+        format!("{}_msaa{}_{:05}ms.png", scene_name, sample_count, millis)
+    } else {
+        format!("{}_{:05}ms.png", scene_name, millis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_images_respects_tolerance() {
+        let expected = RgbaImage::from_pixel(2, 1, Rgba([100, 100, 100, 255]));
+        let mut actual = expected.clone();
+        actual.put_pixel(0, 0, Rgba([108, 100, 100, 255]));
+        actual.put_pixel(1, 0, Rgba([100, 91, 100, 255]));
+
+        let comparison = compare_images(&actual, &expected, 8);
+
+        assert_eq!(comparison.mismatched_pixels, 1);
+        assert_eq!(comparison.max_channel_diff, 9);
+        assert_eq!(
+            comparison.diff_image.get_pixel(0, 0),
+            &Rgba([25, 25, 25, 255])
+        );
+        assert_eq!(
+            comparison.diff_image.get_pixel(1, 0),
+            &Rgba([255, 0, 0, 255])
+        );
+    }
+
+    #[test]
+    fn golden_file_names() {
+        assert_eq!(golden_file_name("quad", 1, 0.5), "quad_00500ms.png");
+        assert_eq!(golden_file_name("cube", 4, 2.5), "cube_msaa4_02500ms.png");
+    }
+}
@@ -0,0 +1,7 @@
+// wgpu와 Vulkan 테스트 크레이트가 함께 사용하는 백엔드 독립적인 코드
+pub mod asset;
+pub mod gltf_loader;
+pub mod golden;
+pub mod pipeline_cache;
+pub mod size;
+pub mod time_source;
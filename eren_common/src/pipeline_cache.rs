@@ -0,0 +1,177 @@
+use std::path::{Path, PathBuf};
+
+#[cfg(target_os = "android")]
+use winit::platform::android::activity::AndroidApp;
+
+// 파이프라인 캐시를 저장할 디렉터리 (예: EREN_PIPELINE_CACHE_DIR=/tmp/eren)
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+pub const PIPELINE_CACHE_DIR_ENV: &str = "EREN_PIPELINE_CACHE_DIR";
+
+// 파일 형식: magic, 디바이스/드라이버 식별 문자열 길이(u32 LE), 식별 문자열, 백엔드 캐시 데이터
+const CACHE_FILE_MAGIC: &[u8; 8] = b"ERENPSO1";
+
+#[derive(Debug, PartialEq)]
+pub enum CacheFileLoad {
+    Hit(Vec<u8>),
+    Miss,
+    // 다른 디바이스/드라이버에서 만들어졌거나 형식이 맞지 않음
+    Invalidated(&'static str),
+}
+
+pub fn read_cache_file(path: &Path, identity: &str) -> CacheFileLoad {
+    let Ok(bytes) = std::fs::read(path) else {
+        return CacheFileLoad::Miss;
+    };
+
+    let Some(rest) = bytes.strip_prefix(CACHE_FILE_MAGIC) else {
+        return CacheFileLoad::Invalidated("unknown file format");
+    };
+    let Some((len, rest)) = rest.split_first_chunk::<4>() else {
+        return CacheFileLoad::Invalidated("truncated header");
+    };
+    let len = u32::from_le_bytes(*len) as usize;
+
+    if rest.len() < len {
+        return CacheFileLoad::Invalidated("truncated header");
+    }
+
+    let (stored_identity, data) = rest.split_at(len);
+    if stored_identity != identity.as_bytes() {
+        return CacheFileLoad::Invalidated("device or driver changed");
+    }
+
+    CacheFileLoad::Hit(data.to_vec())
+}
+
+// 쓰는 도중 종료되어도 깨진 파일이 남지 않도록 임시 파일에 쓴 뒤 이름을 바꿈
+pub fn write_cache_file(path: &Path, identity: &str, data: &[u8]) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let mut bytes = Vec::with_capacity(CACHE_FILE_MAGIC.len() + 4 + identity.len() + data.len());
+    bytes.extend_from_slice(CACHE_FILE_MAGIC);
+    bytes.extend_from_slice(&(identity.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(identity.as_bytes());
+    bytes.extend_from_slice(data);
+
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, bytes)?;
+    std::fs::rename(&temp_path, path)
+}
+
+// Android는 앱 내부 저장소를 사용하므로 asset과 같은 AndroidApp이 필요
+#[cfg(target_os = "android")]
+pub fn default_cache_dir(app: &AndroidApp) -> Option<PathBuf> {
+    Some(app.internal_data_path()?.join("pipeline_cache"))
+}
+
+#[cfg(target_os = "ios")]
+pub fn default_cache_dir(_app_name: &str) -> Option<PathBuf> {
+    let home = std::env::var_os("HOME")?;
+
+    Some(PathBuf::from(home).join("Library/Caches/pipeline_cache"))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn default_cache_dir(_app_name: &str) -> Option<PathBuf> {
+    None
+}
+
+// 플랫폼별 사용자 캐시 디렉터리 아래에 앱(크레이트) 이름으로 디렉터리를 만듦
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+pub fn default_cache_dir(app_name: &str) -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os(PIPELINE_CACHE_DIR_ENV) {
+        return Some(PathBuf::from(dir));
+    }
+
+    let base = if cfg!(target_os = "windows") {
+        PathBuf::from(std::env::var_os("LOCALAPPDATA")?)
+    } else if cfg!(target_os = "macos") {
+        PathBuf::from(std::env::var_os("HOME")?).join("Library/Caches")
+    } else {
+        match std::env::var_os("XDG_CACHE_HOME") {
+            Some(dir) => PathBuf::from(dir),
+            None => PathBuf::from(std::env::var_os("HOME")?).join(".cache"),
+        }
+    };
+
+    Some(base.join(app_name).join("pipeline_cache"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 테스트끼리 병렬로 실행되어도 겹치지 않도록 테스트 이름으로 파일을 나눔
+    fn cache_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("eren_common_test_{}", std::process::id()))
+            .join(format!("{}.bin", name))
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = cache_path("round_trip");
+        write_cache_file(&path, "vendor|device|driver", &[1, 2, 3, 4]).unwrap();
+
+        assert_eq!(
+            read_cache_file(&path, "vendor|device|driver"),
+            CacheFileLoad::Hit(vec![1, 2, 3, 4])
+        );
+        assert!(!path.with_extension("tmp").exists());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn missing_file_is_miss() {
+        let path = cache_path("missing_file_is_miss");
+
+        assert_eq!(read_cache_file(&path, "identity"), CacheFileLoad::Miss);
+    }
+
+    #[test]
+    fn truncated_file_is_invalidated() {
+        let path = cache_path("truncated_file_is_invalidated");
+        write_cache_file(&path, "vendor|device|driver", &[1, 2, 3, 4]).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+
+        // 식별 문자열 중간에서 잘림
+        std::fs::write(&path, &bytes[..CACHE_FILE_MAGIC.len() + 4 + 3]).unwrap();
+        assert_eq!(
+            read_cache_file(&path, "vendor|device|driver"),
+            CacheFileLoad::Invalidated("truncated header")
+        );
+
+        // 길이 필드 중간에서 잘림
+        std::fs::write(&path, &bytes[..CACHE_FILE_MAGIC.len() + 2]).unwrap();
+        assert_eq!(
+            read_cache_file(&path, "vendor|device|driver"),
+            CacheFileLoad::Invalidated("truncated header")
+        );
+
+        // magic 중간에서 잘림
+        std::fs::write(&path, &bytes[..4]).unwrap();
+        assert_eq!(
+            read_cache_file(&path, "vendor|device|driver"),
+            CacheFileLoad::Invalidated("unknown file format")
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn identity_mismatch_is_invalidated() {
+        let path = cache_path("identity_mismatch_is_invalidated");
+        write_cache_file(&path, "vendor|device|driver-1", &[1, 2, 3, 4]).unwrap();
+
+        assert_eq!(
+            read_cache_file(&path, "vendor|device|driver-2"),
+            CacheFileLoad::Invalidated("device or driver changed")
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,127 @@
+use winit::{
+    dpi::{LogicalSize, PhysicalSize},
+    window::Window,
+};
+
+// Surface와 렌더링은 항상 물리 픽셀 크기를 사용하고, 논리 크기는 scale factor로 계산
+// (scale factor를 정수로 잘라 나누면 2.75 같은 값에서 잘못된 크기가 됨)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SurfaceSize {
+    pub physical: PhysicalSize<u32>,
+    pub scale_factor: f64,
+}
+
+impl SurfaceSize {
+    pub fn new(physical: PhysicalSize<u32>, scale_factor: f64) -> Self {
+        Self {
+            physical,
+            scale_factor,
+        }
+    }
+
+    pub fn from_window(window: &Window) -> Self {
+        Self::new(window.inner_size(), window.scale_factor())
+    }
+
+    pub fn logical(&self) -> LogicalSize<f64> {
+        self.physical.to_logical(self.scale_factor)
+    }
+
+    // 최소화 등으로 크기가 0이면 surface를 설정할 수 없음
+    pub fn is_empty(&self) -> bool {
+        self.physical.width == 0 || self.physical.height == 0
+    }
+
+    // 논리 크기를 유지한 채 scale factor만 바꾼 크기
+    // ScaleFactorChanged 이벤트 중에는 창 크기가 아직 갱신되지 않았을 수 있으며,
+    // winit은 기본적으로 논리 크기가 유지되도록 창 크기를 바꿈
+    pub fn rescaled(&self, scale_factor: f64) -> Self {
+        Self::new(self.logical().to_physical(scale_factor), scale_factor)
+    }
+}
+
+// 크기 변경 전후 값과 swapchain/surface에 사용할 새 물리 크기
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SurfaceResize {
+    pub old: SurfaceSize,
+    pub new: SurfaceSize,
+}
+
+impl SurfaceResize {
+    pub fn new(old: SurfaceSize, new: SurfaceSize) -> Self {
+        Self { old, new }
+    }
+
+    // 새 크기가 0이면 다음 resize까지 swapchain을 다시 만들지 않음
+    pub fn extent(&self) -> Option<PhysicalSize<u32>> {
+        (!self.new.is_empty()).then_some(self.new.physical)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn surface_size(width: u32, height: u32, scale_factor: f64) -> SurfaceSize {
+        SurfaceSize::new(PhysicalSize::new(width, height), scale_factor)
+    }
+
+    #[test]
+    fn logical_size_at_integer_scale() {
+        let size = surface_size(400, 300, 1.0);
+
+        assert_eq!(size.physical, PhysicalSize::new(400, 300));
+        assert_eq!(size.logical(), LogicalSize::new(400.0, 300.0));
+    }
+
+    #[test]
+    fn logical_size_at_fractional_scale() {
+        let size = surface_size(600, 450, 1.5);
+
+        assert_eq!(size.physical, PhysicalSize::new(600, 450));
+        assert_eq!(size.logical(), LogicalSize::new(400.0, 300.0));
+    }
+
+    #[test]
+    fn logical_size_is_not_truncated_at_2_75() {
+        let size = surface_size(1100, 825, 2.75);
+
+        // scale factor를 2로 잘라 나누면 550x412가 됨
+        assert_eq!(size.physical, PhysicalSize::new(1100, 825));
+        assert_eq!(size.logical(), LogicalSize::new(400.0, 300.0));
+    }
+
+    #[test]
+    fn minimized_surface_is_empty() {
+        let size = surface_size(0, 0, 2.75);
+
+        assert!(size.is_empty());
+        assert_eq!(size.logical(), LogicalSize::new(0.0, 0.0));
+
+        assert!(surface_size(1100, 0, 2.75).is_empty());
+        assert!(!surface_size(1100, 825, 2.75).is_empty());
+    }
+
+    #[test]
+    fn scale_factor_change_keeps_logical_size() {
+        let old = surface_size(400, 300, 1.0);
+        let new = old.rescaled(2.75);
+        let resize = SurfaceResize::new(old, new);
+
+        assert_eq!(new.physical, PhysicalSize::new(1100, 825));
+        assert_eq!(new.logical(), old.logical());
+        assert_eq!(resize.old.physical, PhysicalSize::new(400, 300));
+        assert_eq!(resize.extent(), Some(PhysicalSize::new(1100, 825)));
+
+        // 다시 1.0으로 돌아오면 원래 물리 크기가 됨
+        let back = SurfaceResize::new(new, new.rescaled(1.0));
+        assert_eq!(back.extent(), Some(PhysicalSize::new(400, 300)));
+    }
+
+    #[test]
+    fn minimized_resize_has_no_extent() {
+        let resize = SurfaceResize::new(surface_size(1100, 825, 2.75), surface_size(0, 0, 2.75));
+
+        assert_eq!(resize.extent(), None);
+    }
+}
@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+
+// 애니메이션에 사용하는 시간을 주입하기 위한 인터페이스
+pub trait TimeSource {
+    // 시작 시점부터 경과한 시간(초)
+    fn elapsed_secs(&self) -> f32;
+}
+
+// 실제 시계 기준 (wasm에서는 std::time::Instant를 사용할 수 없어 chrono 사용)
+pub struct SystemTimeSource {
+    start_time: DateTime<Utc>,
+}
+
+impl SystemTimeSource {
+    pub fn new() -> Self {
+        Self {
+            start_time: Utc::now(),
+        }
+    }
+}
+
+impl Default for SystemTimeSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeSource for SystemTimeSource {
+    fn elapsed_secs(&self) -> f32 {
+        Utc::now()
+            .signed_duration_since(self.start_time)
+            .num_milliseconds() as f32
+            / 1000.0
+    }
+}
+
+// 항상 같은 시간을 반환 (골든 이미지 테스트용)
+pub struct FixedTimeSource {
+    pub secs: f32,
+}
+
+impl FixedTimeSource {
+    pub fn new(secs: f32) -> Self {
+        Self { secs }
+    }
+}
+
+impl TimeSource for FixedTimeSource {
+    fn elapsed_secs(&self) -> f32 {
+        self.secs
+    }
+}
@@ -0,0 +1,160 @@
+// 정점 구조체의 필드 타입과 offset으로 wgpu/Vulkan 정점 레이아웃을 생성
+// wgpu feature는 WgpuVertexLayout, ash feature는 VkVertexLayout 구현을 추가함
+pub use eren_vertex_derive::VertexLayout;
+
+#[cfg(feature = "ash")]
+pub use ash;
+#[cfg(feature = "wgpu")]
+pub use wgpu;
+
+use glam::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
+
+// 정점 attribute로 사용할 수 있는 필드 타입
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a supported vertex attribute type",
+    label = "unsupported vertex attribute type",
+    note = "supported types are f32, u32, i32, their 2-4 element arrays, and glam Vec/UVec/IVec 2-4"
+)]
+pub trait VertexAttributeType {
+    #[cfg(feature = "wgpu")]
+    const WGPU_FORMAT: wgpu::VertexFormat;
+
+    #[cfg(feature = "ash")]
+    const VK_FORMAT: ash::vk::Format;
+}
+
+macro_rules! impl_attribute_type {
+    ($($ty:ty => $wgpu_format:ident, $vk_format:ident;)*) => {
+        $(
+            impl VertexAttributeType for $ty {
+                #[cfg(feature = "wgpu")]
+                const WGPU_FORMAT: wgpu::VertexFormat = wgpu::VertexFormat::$wgpu_format;
+
+                #[cfg(feature = "ash")]
+                const VK_FORMAT: ash::vk::Format = ash::vk::Format::$vk_format;
+            }
+        )*
+    };
+}
+
+impl_attribute_type! {
+    f32 => Float32, R32_SFLOAT;
+    [f32; 2] => Float32x2, R32G32_SFLOAT;
+    [f32; 3] => Float32x3, R32G32B32_SFLOAT;
+    [f32; 4] => Float32x4, R32G32B32A32_SFLOAT;
+    Vec2 => Float32x2, R32G32_SFLOAT;
+    Vec3 => Float32x3, R32G32B32_SFLOAT;
+    Vec4 => Float32x4, R32G32B32A32_SFLOAT;
+    u32 => Uint32, R32_UINT;
+    [u32; 2] => Uint32x2, R32G32_UINT;
+    [u32; 3] => Uint32x3, R32G32B32_UINT;
+    [u32; 4] => Uint32x4, R32G32B32A32_UINT;
+    UVec2 => Uint32x2, R32G32_UINT;
+    UVec3 => Uint32x3, R32G32B32_UINT;
+    UVec4 => Uint32x4, R32G32B32A32_UINT;
+    i32 => Sint32, R32_SINT;
+    [i32; 2] => Sint32x2, R32G32_SINT;
+    [i32; 3] => Sint32x3, R32G32B32_SINT;
+    [i32; 4] => Sint32x4, R32G32B32A32_SINT;
+    IVec2 => Sint32x2, R32G32_SINT;
+    IVec3 => Sint32x3, R32G32B32_SINT;
+    IVec4 => Sint32x4, R32G32B32A32_SINT;
+}
+
+#[cfg(feature = "wgpu")]
+pub trait WgpuVertexLayout {
+    const ATTRIBUTES: &'static [wgpu::VertexAttribute];
+
+    const LAYOUT: wgpu::VertexBufferLayout<'static>;
+}
+
+// binding 0에 정점 단위로 바인딩하는 레이아웃
+#[cfg(feature = "ash")]
+pub trait VkVertexLayout {
+    const BINDING_DESCRIPTION: ash::vk::VertexInputBindingDescription;
+
+    const ATTRIBUTE_DESCRIPTIONS: &'static [ash::vk::VertexInputAttributeDescription];
+}
+
+// derive(VertexLayout)가 생성하는 코드, 직접 사용하지 않음
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_vertex_layout {
+    ($name:ident { $($field:ident: $ty:ty => $location:literal),* }) => {
+        // 지원하지 않는 필드 타입은 활성화된 백엔드와 관계없이 컴파일 에러
+        const _: () = {
+            const fn assert_attribute_type<T: $crate::VertexAttributeType>() {}
+            $(assert_attribute_type::<$ty>();)*
+        };
+
+        $crate::__impl_wgpu_vertex_layout! { $name { $($field: $ty => $location),* } }
+        $crate::__impl_vk_vertex_layout! { $name { $($field: $ty => $location),* } }
+    };
+}
+
+#[cfg(feature = "wgpu")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_wgpu_vertex_layout {
+    ($name:ident { $($field:ident: $ty:ty => $location:literal),* }) => {
+        impl $crate::WgpuVertexLayout for $name {
+            const ATTRIBUTES: &'static [$crate::wgpu::VertexAttribute] = &[
+                $(
+                    $crate::wgpu::VertexAttribute {
+                        format: <$ty as $crate::VertexAttributeType>::WGPU_FORMAT,
+                        offset: ::std::mem::offset_of!($name, $field) as $crate::wgpu::BufferAddress,
+                        shader_location: $location,
+                    },
+                )*
+            ];
+
+            const LAYOUT: $crate::wgpu::VertexBufferLayout<'static> =
+                $crate::wgpu::VertexBufferLayout {
+                    array_stride: ::std::mem::size_of::<$name>() as $crate::wgpu::BufferAddress,
+                    step_mode: $crate::wgpu::VertexStepMode::Vertex,
+                    attributes: <$name as $crate::WgpuVertexLayout>::ATTRIBUTES,
+                };
+        }
+    };
+}
+
+#[cfg(not(feature = "wgpu"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_wgpu_vertex_layout {
+    ($($tokens:tt)*) => {};
+}
+
+#[cfg(feature = "ash")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_vk_vertex_layout {
+    ($name:ident { $($field:ident: $ty:ty => $location:literal),* }) => {
+        impl $crate::VkVertexLayout for $name {
+            const BINDING_DESCRIPTION: $crate::ash::vk::VertexInputBindingDescription =
+                $crate::ash::vk::VertexInputBindingDescription {
+                    binding: 0,
+                    stride: ::std::mem::size_of::<$name>() as u32,
+                    input_rate: $crate::ash::vk::VertexInputRate::VERTEX,
+                };
+
+            const ATTRIBUTE_DESCRIPTIONS: &'static [$crate::ash::vk::VertexInputAttributeDescription] = &[
+                $(
+                    $crate::ash::vk::VertexInputAttributeDescription {
+                        location: $location,
+                        binding: 0,
+                        format: <$ty as $crate::VertexAttributeType>::VK_FORMAT,
+                        offset: ::std::mem::offset_of!($name, $field) as u32,
+                    },
+                )*
+            ];
+        }
+    };
+}
+
+#[cfg(not(feature = "ash"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_vk_vertex_layout {
+    ($($tokens:tt)*) => {};
+}
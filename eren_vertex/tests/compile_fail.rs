@@ -0,0 +1,6 @@
+// derive(VertexLayout)가 거부해야 하는 입력, 에러 메시지는 tests/compile_fail/*.stderr와 비교
+// 메시지를 바꾼 뒤에는 TRYBUILD=overwrite cargo test로 갱신
+#[test]
+fn compile_fail() {
+    trybuild::TestCases::new().compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,9 @@
+use eren_vertex::VertexLayout;
+
+#[repr(C)]
+#[derive(VertexLayout)]
+struct Vertex<T> {
+    pos: T,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use eren_vertex::VertexLayout;
+
+#[derive(VertexLayout)]
+struct Vertex {
+    pos: [f32; 3],
+}
+
+fn main() {}
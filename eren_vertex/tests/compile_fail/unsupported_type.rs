@@ -0,0 +1,10 @@
+use eren_vertex::VertexLayout;
+
+#[repr(C)]
+#[derive(VertexLayout)]
+struct Vertex {
+    pos: [f32; 3],
+    weight: f64,
+}
+
+fn main() {}
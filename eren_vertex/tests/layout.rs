@@ -0,0 +1,78 @@
+// 백엔드별 레이아웃은 feature가 켜진 경우에만 생성되므로 cargo test --all-features로 실행
+
+use eren_vertex::VertexLayout;
+use glam::{Vec2, Vec3};
+
+// eren과 eren_vulkan의 테스트 Vertex와 같은 구조체
+#[repr(C)]
+#[derive(Clone, Copy, VertexLayout)]
+#[allow(dead_code)]
+struct Vertex {
+    pos: Vec3,
+    color: Vec3,
+    tex_coord: Vec2,
+}
+
+#[cfg(feature = "wgpu")]
+#[test]
+fn wgpu_layout() {
+    use eren_vertex::{WgpuVertexLayout, wgpu};
+
+    assert_eq!(Vertex::LAYOUT.array_stride, 32);
+    assert_eq!(Vertex::LAYOUT.step_mode, wgpu::VertexStepMode::Vertex);
+    assert_eq!(
+        Vertex::ATTRIBUTES,
+        [
+            wgpu::VertexAttribute {
+                format: wgpu::VertexFormat::Float32x3,
+                offset: 0,
+                shader_location: 0,
+            },
+            wgpu::VertexAttribute {
+                format: wgpu::VertexFormat::Float32x3,
+                offset: 12,
+                shader_location: 1,
+            },
+            wgpu::VertexAttribute {
+                format: wgpu::VertexFormat::Float32x2,
+                offset: 24,
+                shader_location: 2,
+            },
+        ]
+    );
+}
+
+#[cfg(feature = "ash")]
+#[test]
+fn vk_layout() {
+    use eren_vertex::{VkVertexLayout, ash::vk};
+
+    assert_eq!(Vertex::BINDING_DESCRIPTION.binding, 0);
+    assert_eq!(Vertex::BINDING_DESCRIPTION.stride, 32);
+    assert_eq!(
+        Vertex::BINDING_DESCRIPTION.input_rate.as_raw(),
+        vk::VertexInputRate::VERTEX.as_raw()
+    );
+
+    // ash 구조체는 PartialEq를, debug feature 없이는 Debug도 구현하지 않으므로 raw 값으로 비교
+    let attributes: Vec<_> = Vertex::ATTRIBUTE_DESCRIPTIONS
+        .iter()
+        .map(|attribute| {
+            (
+                attribute.location,
+                attribute.binding,
+                attribute.format.as_raw(),
+                attribute.offset,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        attributes,
+        [
+            (0, 0, vk::Format::R32G32B32_SFLOAT.as_raw(), 0),
+            (1, 0, vk::Format::R32G32B32_SFLOAT.as_raw(), 12),
+            (2, 0, vk::Format::R32G32_SFLOAT.as_raw(), 24),
+        ]
+    );
+}
@@ -0,0 +1,97 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, parse_macro_input, spanned::Spanned};
+
+// 필드 선언 순서대로 shader location 0, 1, 2, ...을 부여하고
+// 백엔드별 레이아웃 구현은 eren_vertex의 macro_rules에 맡김 (활성화된 feature에 따라 생성됨)
+#[proc_macro_derive(VertexLayout)]
+pub fn derive_vertex_layout(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "VertexLayout does not support generic structs",
+        ));
+    }
+
+    if !has_repr_c(input)? {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "VertexLayout requires #[repr(C)] so that the GPU sees the same field layout",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    data.fields.span(),
+                    "VertexLayout requires a struct with named fields",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "VertexLayout can only be derived for structs",
+            ));
+        }
+    };
+
+    if fields.is_empty() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "VertexLayout requires at least one field",
+        ));
+    }
+
+    let name = &input.ident;
+    let attributes = fields.iter().enumerate().map(|(location, field)| {
+        let field_name = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let location = location as u32;
+
+        quote! { #field_name: #ty => #location }
+    });
+
+    Ok(quote! {
+        ::eren_vertex::__impl_vertex_layout! {
+            #name { #(#attributes),* }
+        }
+    })
+}
+
+fn has_repr_c(input: &DeriveInput) -> syn::Result<bool> {
+    let mut repr_c = false;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                repr_c = true;
+            }
+
+            // align(N) 같은 인자는 무시
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(repr_c)
+}
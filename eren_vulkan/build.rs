@@ -0,0 +1,316 @@
+// android/app/src/main/assets/shaders/의 SPIR-V가 셰이더 소스와 일치하는지 검사
+// 장면 셰이더는 eren 크레이트의 shader.wgsl 하나를 소스로 사용하며, 불일치 시 빌드 실패
+// EREN_UPDATE_SHADERS=1로 빌드하면 naga로 만드는 SPIR-V를 다시 생성하고,
+// glslc로 다시 컴파일한 셰이더의 해시를 prebuilt.txt에 기록 (GLSL만 바뀌고 SPIR-V가 그대로면 실패)
+// 갱신 시 소스 트리의 파일을 직접 덮어쓰므로 결과를 확인한 뒤 커밋해야 함
+// 생성 결과가 naga 버전에 따라 달라지므로 Cargo.toml에서 naga 버전을 고정
+// 장면 셰이더가 eren 크레이트 디렉터리에 있으므로 이 크레이트만 따로 빌드하거나 패키징할 수 없음
+use std::{fs, path::Path};
+
+use naga::{
+    ShaderStage,
+    back::spv,
+    valid::{Capabilities, ValidationFlags, Validator},
+};
+
+const UPDATE_SHADERS_ENV: &str = "EREN_UPDATE_SHADERS";
+
+const SPIRV_DIR: &str = "android/app/src/main/assets/shaders";
+
+const GLSL_DIR: &str = "src/test_vertex_input/shaders";
+
+// naga가 subpassInput을 지원하지 않아 glslc로 컴파일해 두는 셰이더의 해시 목록
+const PREBUILT_MANIFEST: &str = "src/test_vertex_input/shaders/prebuilt.txt";
+
+const SCENE_SHADER: &str = "../eren/android/app/src/main/assets/shaders/shader.wgsl";
+
+enum ShaderSource {
+    // 여러 entry point 중 하나를 main으로 내보냄
+    Wgsl {
+        path: &'static str,
+        entry_point: &'static str,
+    },
+    Glsl {
+        path: &'static str,
+    },
+}
+
+impl ShaderSource {
+    fn path(&self) -> &'static str {
+        match self {
+            ShaderSource::Wgsl { path, .. } | ShaderSource::Glsl { path } => path,
+        }
+    }
+}
+
+struct GeneratedShader {
+    source: ShaderSource,
+    stage: ShaderStage,
+    output: &'static str,
+}
+
+const GENERATED_SHADERS: [GeneratedShader; 3] = [
+    GeneratedShader {
+        source: ShaderSource::Wgsl {
+            path: SCENE_SHADER,
+            entry_point: "vs_main",
+        },
+        stage: ShaderStage::Vertex,
+        output: "shader.vert.spv",
+    },
+    GeneratedShader {
+        source: ShaderSource::Wgsl {
+            path: SCENE_SHADER,
+            entry_point: "fs_main",
+        },
+        stage: ShaderStage::Fragment,
+        output: "shader.frag.spv",
+    },
+    GeneratedShader {
+        source: ShaderSource::Glsl {
+            path: "src/test_vertex_input/shaders/post_process.vert",
+        },
+        stage: ShaderStage::Vertex,
+        output: "post_process.vert.spv",
+    },
+];
+
+fn main() {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let manifest_dir = Path::new(&manifest_dir);
+    let update = std::env::var_os(UPDATE_SHADERS_ENV).is_some_and(|value| value != "0");
+
+    println!("cargo:rerun-if-env-changed={}", UPDATE_SHADERS_ENV);
+    println!("cargo:rerun-if-changed={}", SPIRV_DIR);
+    println!("cargo:rerun-if-changed={}", GLSL_DIR);
+    println!("cargo:rerun-if-changed={}", SCENE_SHADER);
+
+    let generated_errors: Vec<String> = GENERATED_SHADERS
+        .iter()
+        .filter_map(|shader| check_generated_shader(manifest_dir, shader, update).err())
+        .collect();
+
+    let prebuilt_errors = check_prebuilt_shaders(manifest_dir, update)
+        .err()
+        .unwrap_or_default();
+
+    // 두 종류의 셰이더는 갱신 방법이 다르므로 각각 안내
+    if !generated_errors.is_empty() {
+        for e in &generated_errors {
+            eprintln!("error: {}", e);
+        }
+        eprintln!(
+            "Rebuild with {}=1 to regenerate the naga-generated SPIR-V in {}",
+            UPDATE_SHADERS_ENV, SPIRV_DIR
+        );
+    }
+
+    if !prebuilt_errors.is_empty() {
+        for e in &prebuilt_errors {
+            eprintln!("error: {}", e);
+        }
+        eprintln!(
+            "Recompile the shaders listed in {} with glslc, then rebuild with {}=1 to record the new hashes",
+            PREBUILT_MANIFEST, UPDATE_SHADERS_ENV
+        );
+    }
+
+    if !generated_errors.is_empty() || !prebuilt_errors.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+fn check_generated_shader(
+    manifest_dir: &Path,
+    shader: &GeneratedShader,
+    update: bool,
+) -> Result<(), String> {
+    let source_name = shader.source.path();
+    let source = fs::read_to_string(manifest_dir.join(source_name))
+        .map_err(|e| format!("Failed to read {}: {}", source_name, e))?;
+
+    let module = match shader.source {
+        ShaderSource::Wgsl { entry_point, .. } => {
+            let mut module = naga::front::wgsl::parse_str(&source).map_err(|e| {
+                format!(
+                    "Failed to parse {}:\n{}",
+                    source_name,
+                    e.emit_to_string(&source)
+                )
+            })?;
+
+            // Vulkan 파이프라인은 entry point 이름으로 main을 사용
+            module
+                .entry_points
+                .retain(|ep| ep.name == entry_point && ep.stage == shader.stage);
+            let Some(ep) = module.entry_points.first_mut() else {
+                return Err(format!(
+                    "{} has no {:?} entry point named {}",
+                    source_name, shader.stage, entry_point
+                ));
+            };
+            ep.name = "main".to_string();
+
+            module
+        }
+        ShaderSource::Glsl { .. } => naga::front::glsl::Frontend::default()
+            .parse(&naga::front::glsl::Options::from(shader.stage), &source)
+            .map_err(|e| {
+                format!(
+                    "Failed to parse {}:\n{}",
+                    source_name,
+                    e.emit_to_string(&source)
+                )
+            })?,
+    };
+
+    let info = Validator::new(ValidationFlags::all(), Capabilities::empty())
+        .validate(&module)
+        .map_err(|e| {
+            format!(
+                "Failed to validate {}:\n{}",
+                source_name,
+                e.emit_to_string(&source)
+            )
+        })?;
+
+    // 프로젝션 행렬에서 Y축을 직접 뒤집으므로 좌표계 보정은 하지 않음
+    let options = spv::Options {
+        lang_version: (1, 0),
+        flags: spv::WriterFlags::empty(),
+        ..Default::default()
+    };
+    let pipeline_options = spv::PipelineOptions {
+        shader_stage: shader.stage,
+        entry_point: "main".to_string(),
+    };
+
+    let words = spv::write_vec(&module, &info, &options, Some(&pipeline_options))
+        .map_err(|e| format!("Failed to write SPIR-V for {}: {}", source_name, e))?;
+    let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+
+    let output_name = format!("{}/{}", SPIRV_DIR, shader.output);
+    let output_path = manifest_dir.join(&output_name);
+
+    if fs::read(&output_path).ok().as_deref() == Some(bytes.as_slice()) {
+        return Ok(());
+    }
+
+    if !update {
+        return Err(format!(
+            "{} is out of date with {}",
+            output_name, source_name
+        ));
+    }
+
+    fs::write(&output_path, &bytes)
+        .map_err(|e| format!("Failed to write {}: {}", output_name, e))?;
+    println!("cargo:warning=Updated {}", output_name);
+
+    Ok(())
+}
+
+// 각 줄은 "<GLSL 파일 이름> <GLSL 해시> <SPIR-V 해시>"이며 SPIR-V 파일은 <GLSL 파일 이름>.spv
+fn check_prebuilt_shaders(manifest_dir: &Path, update: bool) -> Result<(), Vec<String>> {
+    let manifest = fs::read_to_string(manifest_dir.join(PREBUILT_MANIFEST))
+        .map_err(|e| vec![format!("Failed to read {}: {}", PREBUILT_MANIFEST, e)])?;
+
+    let mut updated = String::new();
+    let mut errors = Vec::new();
+
+    for line in manifest.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+
+        let [name, source_hash, spirv_hash] = fields[..] else {
+            // 주석과 빈 줄은 그대로 유지
+            if line.trim().is_empty() || line.starts_with('#') {
+                updated.push_str(line);
+                updated.push('\n');
+            } else {
+                errors.push(format!("Invalid line in {}: {}", PREBUILT_MANIFEST, line));
+            }
+            continue;
+        };
+
+        let source_name = format!("{}/{}", GLSL_DIR, name);
+        let spirv_name = format!("{}/{}.spv", SPIRV_DIR, name);
+
+        let source = match fs::read(manifest_dir.join(&source_name)) {
+            Ok(source) => source,
+            Err(e) => {
+                errors.push(format!("Failed to read {}: {}", source_name, e));
+                continue;
+            }
+        };
+        let spirv = match fs::read(manifest_dir.join(&spirv_name)) {
+            Ok(spirv) => spirv,
+            Err(e) => {
+                errors.push(format!("Failed to read {}: {}", spirv_name, e));
+                continue;
+            }
+        };
+
+        if let Err(e) = validate_spirv_header(&spirv) {
+            errors.push(format!("Invalid SPIR-V {}: {}", spirv_name, e));
+            continue;
+        }
+
+        let actual_source_hash = format!("{:016x}", fnv1a(&source));
+        let actual_spirv_hash = format!("{:016x}", fnv1a(&spirv));
+
+        let source_changed = actual_source_hash != source_hash;
+        let spirv_changed = actual_spirv_hash != spirv_hash;
+
+        // 갱신 모드에서도 GLSL만 바뀌었다면 SPIR-V를 다시 컴파일하지 않은 것이므로 기록하지 않음
+        if source_changed && !spirv_changed {
+            errors.push(format!(
+                "{} changed, but {} was not recompiled",
+                source_name, spirv_name
+            ));
+        } else if !update && spirv_changed {
+            errors.push(format!(
+                "{} does not match the hash recorded in {}",
+                spirv_name, PREBUILT_MANIFEST
+            ));
+        }
+
+        updated.push_str(&format!(
+            "{} {} {}\n",
+            name, actual_source_hash, actual_spirv_hash
+        ));
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    if update && updated != manifest {
+        fs::write(manifest_dir.join(PREBUILT_MANIFEST), updated)
+            .map_err(|e| vec![format!("Failed to write {}: {}", PREBUILT_MANIFEST, e)])?;
+        println!("cargo:warning=Updated {}", PREBUILT_MANIFEST);
+    }
+
+    Ok(())
+}
+
+fn validate_spirv_header(spirv: &[u8]) -> Result<(), String> {
+    const SPIRV_MAGIC: u32 = 0x0723_0203;
+
+    if spirv.len() < 20 || !spirv.len().is_multiple_of(4) {
+        return Err(format!("unexpected size {}", spirv.len()));
+    }
+
+    let magic = u32::from_le_bytes([spirv[0], spirv[1], spirv[2], spirv[3]]);
+    if magic != SPIRV_MAGIC {
+        return Err(format!("unexpected magic number {:#010x}", magic));
+    }
+
+    Ok(())
+}
+
+// 빌드 환경과 관계없이 같은 값이 나오는 64비트 FNV-1a 해시
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
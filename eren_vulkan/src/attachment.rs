@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use ash::vk;
+use eren_vulkan_render_shared::device::{
+    Device, ImageViewCreationError, ImageWithMemoryCreationError,
+};
+use thiserror::Error;
+
+pub const DEPTH_FORMAT: vk::Format = vk::Format::D32_SFLOAT;
+
+#[derive(Debug, Error)]
+pub enum ImageAttachmentCreationError {
+    #[error("Failed to create image with memory: {0}")]
+    CreateImageWithMemory(#[from] ImageWithMemoryCreationError),
+
+    #[error("Failed to create image view: {0}")]
+    CreateImageView(#[from] ImageViewCreationError),
+}
+
+// framebuffer마다 따로 할당하는 attachment 이미지 (depth, subpass 간 중간 color, MSAA color 등)
+pub struct ImageAttachment {
+    device: Arc<Device>,
+    image: vk::Image,
+    memory: vk::DeviceMemory,
+    view: vk::ImageView,
+}
+
+impl ImageAttachment {
+    pub fn new(
+        device: Arc<Device>,
+        extent: vk::Extent2D,
+        format: vk::Format,
+        samples: vk::SampleCountFlags,
+        usage: vk::ImageUsageFlags,
+        aspect: vk::ImageAspectFlags,
+        memory_properties: vk::MemoryPropertyFlags,
+    ) -> Result<Self, ImageAttachmentCreationError> {
+        let (image, memory) = device.create_multisampled_image_with_memory(
+            extent.width,
+            extent.height,
+            format,
+            samples,
+            usage,
+            memory_properties,
+        )?;
+
+        let view = match device.create_image_view(image, format, aspect) {
+            Ok(view) => view,
+            Err(e) => {
+                device.destroy_image_with_memory(image, memory);
+                return Err(e.into());
+            }
+        };
+
+        Ok(Self {
+            device,
+            image,
+            memory,
+            view,
+        })
+    }
+
+    pub fn view(&self) -> vk::ImageView {
+        self.view
+    }
+}
+
+impl Drop for ImageAttachment {
+    fn drop(&mut self) {
+        self.device.destroy_image_view(self.view);
+        self.device
+            .destroy_image_with_memory(self.image, self.memory);
+    }
+}
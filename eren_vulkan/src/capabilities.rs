@@ -0,0 +1,343 @@
+use std::ffi::CStr;
+
+use ash::vk;
+use eren_vulkan_render_shared::{
+    device::{Device, RenderPassCreationError},
+    instance::Instance,
+    physical_device::PhysicalDevice,
+};
+
+use crate::{
+    debug_utils::{DEBUG_UTILS_COMPILED, DebugUtils},
+    msaa,
+};
+
+// 인스턴스를 만들 때 요청할 API 버전 상한 (예: EREN_VULKAN_API_VERSION=1.1)
+// lavapipe처럼 1.3을 지원하는 드라이버에서 구형 디바이스 경로를 재현할 때 사용
+pub const API_VERSION_ENV: &str = "EREN_VULKAN_API_VERSION";
+
+pub const DEFAULT_API_VERSION: u32 = vk::API_VERSION_1_3;
+
+pub fn max_api_version() -> u32 {
+    let Ok(value) = std::env::var(API_VERSION_ENV) else {
+        return DEFAULT_API_VERSION;
+    };
+
+    match parse_api_version(&value) {
+        Some(version) => version.min(DEFAULT_API_VERSION),
+        None => {
+            log::warn!(
+                "Invalid {} value: {:?}, using {}",
+                API_VERSION_ENV,
+                value,
+                format_api_version(DEFAULT_API_VERSION)
+            );
+            DEFAULT_API_VERSION
+        }
+    }
+}
+
+fn parse_api_version(value: &str) -> Option<u32> {
+    let (major, minor) = value.trim().split_once('.')?;
+
+    Some(vk::make_api_version(
+        0,
+        major.parse().ok()?,
+        minor.parse().ok()?,
+        0,
+    ))
+}
+
+pub fn format_api_version(version: u32) -> String {
+    format!(
+        "{}.{}.{}",
+        vk::api_version_major(version),
+        vk::api_version_minor(version),
+        vk::api_version_patch(version)
+    )
+}
+
+// 요청한 기능 대신 대체 경로를 사용하게 된 항목
+pub struct Downgrade {
+    pub feature: &'static str,
+    pub fallback: &'static str,
+    pub reason: String,
+}
+
+// 인스턴스와 물리 디바이스가 함께 지원하는 기능
+pub struct VulkanCapabilities {
+    pub api_version: u32,
+    pub render_pass2: bool,
+    // color/depth attachment가 함께 지원하는 샘플 수 중 요청 값 이하의 최댓값
+    pub sample_count: vk::SampleCountFlags,
+    // color/depth attachment가 함께 지원하는 샘플 수
+    pub supported_sample_counts: vk::SampleCountFlags,
+    // 타일 메모리에만 존재하는 transient attachment용 메모리 타입 지원 여부
+    pub lazily_allocated_memory: bool,
+    // 객체 이름과 command buffer label, VK_EXT_debug_utils가 없으면 아무것도 하지 않음
+    pub debug_utils: DebugUtils,
+    pub downgrades: Vec<Downgrade>,
+}
+
+impl VulkanCapabilities {
+    pub fn detect(
+        instance: &Instance,
+        physical_device: &PhysicalDevice,
+        device: &Device,
+        instance_extensions: &[&CStr],
+    ) -> Self {
+        // 인스턴스와 디바이스 중 낮은 버전까지만 사용할 수 있음
+        let api_version = instance.api_version().min(physical_device.api_version());
+        let version_name = format_api_version(api_version);
+
+        let mut downgrades = Vec::new();
+
+        if api_version < DEFAULT_API_VERSION {
+            downgrades.push(Downgrade {
+                feature: "Vulkan 1.3",
+                fallback: "Vulkan 1.1/1.2 core",
+                reason: format!("API version is {}", version_name),
+            });
+        }
+
+        // vkCreateRenderPass2와 SubpassDependency2는 1.2부터 core
+        let render_pass2 = api_version >= vk::API_VERSION_1_2;
+
+        if !render_pass2 {
+            downgrades.push(Downgrade {
+                feature: "vkCreateRenderPass2",
+                fallback: "vkCreateRenderPass",
+                reason: format!("API version is {}", version_name),
+            });
+        }
+
+        let limits = physical_device.properties().limits;
+        let supported_sample_counts =
+            limits.framebuffer_color_sample_counts & limits.framebuffer_depth_sample_counts;
+
+        let requested_sample_count = msaa::requested_sample_count();
+        let sample_count =
+            msaa::select_sample_count(requested_sample_count, supported_sample_counts);
+
+        if msaa::sample_count_value(sample_count) < requested_sample_count {
+            downgrades.push(Downgrade {
+                feature: "MSAA",
+                fallback: "lower sample count",
+                reason: format!(
+                    "{}x requested, {}x supported by framebuffer limits",
+                    requested_sample_count,
+                    msaa::sample_count_value(sample_count)
+                ),
+            });
+        }
+
+        // 데스크톱 GPU는 대부분 lazily allocated 메모리 타입이 없음
+        let memory_properties = physical_device.memory_properties();
+        let lazily_allocated_memory = memory_properties.memory_types
+            [..memory_properties.memory_type_count as usize]
+            .iter()
+            .any(|memory_type| {
+                memory_type
+                    .property_flags
+                    .contains(vk::MemoryPropertyFlags::LAZILY_ALLOCATED)
+            });
+
+        if !lazily_allocated_memory {
+            downgrades.push(Downgrade {
+                feature: "Lazily allocated transient attachments",
+                fallback: "device-local memory",
+                reason: "no LAZILY_ALLOCATED memory type".to_string(),
+            });
+        }
+
+        let debug_utils = DebugUtils::new(instance, device, instance_extensions);
+
+        if !debug_utils.is_enabled() {
+            downgrades.push(Downgrade {
+                feature: "Debug names and labels",
+                fallback: "unnamed objects",
+                reason: if DEBUG_UTILS_COMPILED {
+                    "VK_EXT_debug_utils is not available".to_string()
+                } else {
+                    "debug-utils is compiled out in release builds".to_string()
+                },
+            });
+        }
+
+        Self {
+            api_version,
+            render_pass2,
+            sample_count,
+            supported_sample_counts,
+            lazily_allocated_memory,
+            debug_utils,
+            downgrades,
+        }
+    }
+
+    // golden 테스트처럼 환경 변수와 다른 샘플 수로 렌더러를 만들 때 사용
+    // 이후에 만드는 렌더러부터 적용되며, 지원하지 않으면 더 낮은 샘플 수로 대체
+    pub fn set_requested_sample_count(&mut self, requested: u32) {
+        self.sample_count = msaa::select_sample_count(requested, self.supported_sample_counts);
+    }
+
+    pub fn log_report(&self) {
+        log::info!(
+            "Vulkan API version: {}",
+            format_api_version(self.api_version)
+        );
+        log::info!(
+            "MSAA sample count: {}x",
+            msaa::sample_count_value(self.sample_count)
+        );
+
+        if self.downgrades.is_empty() {
+            log::info!("No Vulkan features downgraded");
+            return;
+        }
+
+        for downgrade in &self.downgrades {
+            log::warn!(
+                "Vulkan feature downgraded: {} -> {} ({})",
+                downgrade.feature,
+                downgrade.fallback,
+                downgrade.reason
+            );
+        }
+    }
+
+    // render pass 안에서만 사용되는 attachment의 메모리 속성
+    pub fn transient_memory_properties(&self) -> vk::MemoryPropertyFlags {
+        if self.lazily_allocated_memory {
+            vk::MemoryPropertyFlags::DEVICE_LOCAL | vk::MemoryPropertyFlags::LAZILY_ALLOCATED
+        } else {
+            vk::MemoryPropertyFlags::DEVICE_LOCAL
+        }
+    }
+
+    // render pass 2를 지원하지 않으면 v1 구조체로 변환하여 vkCreateRenderPass 사용
+    pub fn create_render_pass(
+        &self,
+        device: &Device,
+        attachments: &[vk::AttachmentDescription2],
+        subpasses: &[vk::SubpassDescription2],
+        dependencies: &[vk::SubpassDependency2],
+    ) -> Result<vk::RenderPass, RenderPassCreationError> {
+        if self.render_pass2 {
+            return device.create_render_pass(attachments, subpasses, dependencies);
+        }
+
+        let attachments_v1: Vec<vk::AttachmentDescription> = attachments
+            .iter()
+            .map(|attachment| vk::AttachmentDescription {
+                flags: attachment.flags,
+                format: attachment.format,
+                samples: attachment.samples,
+                load_op: attachment.load_op,
+                store_op: attachment.store_op,
+                stencil_load_op: attachment.stencil_load_op,
+                stencil_store_op: attachment.stencil_store_op,
+                initial_layout: attachment.initial_layout,
+                final_layout: attachment.final_layout,
+            })
+            .collect();
+
+        let subpass_refs: Vec<SubpassReferencesV1> =
+            subpasses.iter().map(SubpassReferencesV1::new).collect();
+
+        let subpasses_v1: Vec<vk::SubpassDescription> = subpasses
+            .iter()
+            .zip(subpass_refs.iter())
+            .map(|(subpass, refs)| refs.description(subpass))
+            .collect();
+
+        let dependencies_v1: Vec<vk::SubpassDependency> = dependencies
+            .iter()
+            .map(|dependency| vk::SubpassDependency {
+                src_subpass: dependency.src_subpass,
+                dst_subpass: dependency.dst_subpass,
+                src_stage_mask: dependency.src_stage_mask,
+                dst_stage_mask: dependency.dst_stage_mask,
+                src_access_mask: dependency.src_access_mask,
+                dst_access_mask: dependency.dst_access_mask,
+                dependency_flags: dependency.dependency_flags,
+            })
+            .collect();
+
+        device.create_render_pass_v1(&attachments_v1, &subpasses_v1, &dependencies_v1)
+    }
+}
+
+// SubpassDescription2의 attachment reference를 v1으로 변환하여 보관
+struct SubpassReferencesV1 {
+    input: Vec<vk::AttachmentReference>,
+    color: Vec<vk::AttachmentReference>,
+    resolve: Vec<vk::AttachmentReference>,
+    depth_stencil: Option<vk::AttachmentReference>,
+    preserve: Vec<u32>,
+}
+
+fn to_v1_refs(ptr: *const vk::AttachmentReference2, count: u32) -> Vec<vk::AttachmentReference> {
+    if ptr.is_null() || count == 0 {
+        return Vec::new();
+    }
+
+    // SubpassDescription2가 빌려온 슬라이스를 가리키므로 count만큼 유효함
+    unsafe { std::slice::from_raw_parts(ptr, count as usize) }
+        .iter()
+        .map(|reference| vk::AttachmentReference {
+            attachment: reference.attachment,
+            layout: reference.layout,
+        })
+        .collect()
+}
+
+impl SubpassReferencesV1 {
+    fn new(subpass: &vk::SubpassDescription2) -> Self {
+        let depth_stencil = to_v1_refs(subpass.p_depth_stencil_attachment, 1)
+            .into_iter()
+            .next();
+
+        let preserve = if subpass.p_preserve_attachments.is_null() {
+            Vec::new()
+        } else {
+            unsafe {
+                std::slice::from_raw_parts(
+                    subpass.p_preserve_attachments,
+                    subpass.preserve_attachment_count as usize,
+                )
+            }
+            .to_vec()
+        };
+
+        Self {
+            input: to_v1_refs(subpass.p_input_attachments, subpass.input_attachment_count),
+            color: to_v1_refs(subpass.p_color_attachments, subpass.color_attachment_count),
+            resolve: to_v1_refs(
+                subpass.p_resolve_attachments,
+                subpass.color_attachment_count,
+            ),
+            depth_stencil,
+            preserve,
+        }
+    }
+
+    fn description(&self, subpass: &vk::SubpassDescription2) -> vk::SubpassDescription<'_> {
+        let mut description = vk::SubpassDescription::default()
+            .flags(subpass.flags)
+            .pipeline_bind_point(subpass.pipeline_bind_point)
+            .input_attachments(&self.input)
+            .color_attachments(&self.color)
+            .preserve_attachments(&self.preserve);
+
+        if !self.resolve.is_empty() {
+            description = description.resolve_attachments(&self.resolve);
+        }
+
+        if let Some(depth_stencil) = self.depth_stencil.as_ref() {
+            description = description.depth_stencil_attachment(depth_stencil);
+        }
+
+        description
+    }
+}
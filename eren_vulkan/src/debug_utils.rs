@@ -0,0 +1,100 @@
+use std::ffi::CStr;
+
+use ash::vk;
+use eren_vulkan_render_shared::{device::Device, instance::Instance};
+
+// 디버그 빌드 또는 debug-utils feature를 켠 빌드에서만 컴파일됨
+// 릴리스 빌드에서는 VK_EXT_debug_utils를 요청하지 않음
+pub const DEBUG_UTILS_COMPILED: bool = cfg!(any(feature = "debug-utils", debug_assertions));
+
+// RenderDoc 등 캡처 도구에 표시할 객체 이름과 command buffer label
+// DEBUG_UTILS_COMPILED가 false이면 모든 호출이 빈 함수로 컴파일되고,
+// 인스턴스에 VK_EXT_debug_utils가 켜져 있지 않으면 아무것도 하지 않음
+#[derive(Clone)]
+pub struct DebugUtils {
+    #[cfg(any(feature = "debug-utils", debug_assertions))]
+    loader: Option<ash::ext::debug_utils::Device>,
+}
+
+// label 색상 (RGBA)
+pub const SCENE_LABEL_COLOR: [f32; 4] = [0.2, 0.6, 1.0, 1.0];
+pub const POST_PROCESS_LABEL_COLOR: [f32; 4] = [1.0, 0.6, 0.2, 1.0];
+pub const RENDER_PASS_LABEL_COLOR: [f32; 4] = [0.6, 0.6, 0.6, 1.0];
+
+impl DebugUtils {
+    #[cfg(any(feature = "debug-utils", debug_assertions))]
+    pub fn new(instance: &Instance, device: &Device, instance_extensions: &[&CStr]) -> Self {
+        let loader = instance_extensions
+            .contains(&ash::ext::debug_utils::NAME)
+            .then(|| ash::ext::debug_utils::Device::new(instance.raw(), device.raw()));
+
+        Self { loader }
+    }
+
+    #[cfg(not(any(feature = "debug-utils", debug_assertions)))]
+    pub fn new(_instance: &Instance, _device: &Device, _instance_extensions: &[&CStr]) -> Self {
+        Self {}
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        #[cfg(any(feature = "debug-utils", debug_assertions))]
+        return self.loader.is_some();
+
+        #[cfg(not(any(feature = "debug-utils", debug_assertions)))]
+        return false;
+    }
+
+    #[cfg(any(feature = "debug-utils", debug_assertions))]
+    pub fn set_name<H: vk::Handle>(&self, handle: H, name: &str) {
+        let Some(loader) = &self.loader else {
+            return;
+        };
+        let Ok(name) = std::ffi::CString::new(name) else {
+            return;
+        };
+
+        let info = vk::DebugUtilsObjectNameInfoEXT::default()
+            .object_handle(handle)
+            .object_name(&name);
+
+        // 이름은 디버깅용이므로 실패해도 무시
+        if let Err(e) = unsafe { loader.set_debug_utils_object_name(&info) } {
+            log::debug!("Failed to set debug name {:?}: {}", name, e);
+        }
+    }
+
+    #[cfg(not(any(feature = "debug-utils", debug_assertions)))]
+    #[inline(always)]
+    pub fn set_name<H: vk::Handle>(&self, _handle: H, _name: &str) {}
+
+    // 같은 command buffer에서 end_label과 짝을 맞춰 호출
+    #[cfg(any(feature = "debug-utils", debug_assertions))]
+    pub fn begin_label(&self, command_buffer: vk::CommandBuffer, name: &str, color: [f32; 4]) {
+        let Some(loader) = &self.loader else {
+            return;
+        };
+        // end_label과 짝이 맞도록 이름이 잘못되어도 label은 시작
+        let name = std::ffi::CString::new(name).unwrap_or_default();
+
+        let label = vk::DebugUtilsLabelEXT::default()
+            .label_name(&name)
+            .color(color);
+
+        unsafe { loader.cmd_begin_debug_utils_label(command_buffer, &label) };
+    }
+
+    #[cfg(not(any(feature = "debug-utils", debug_assertions)))]
+    #[inline(always)]
+    pub fn begin_label(&self, _command_buffer: vk::CommandBuffer, _name: &str, _color: [f32; 4]) {}
+
+    #[cfg(any(feature = "debug-utils", debug_assertions))]
+    pub fn end_label(&self, command_buffer: vk::CommandBuffer) {
+        if let Some(loader) = &self.loader {
+            unsafe { loader.cmd_end_debug_utils_label(command_buffer) };
+        }
+    }
+
+    #[cfg(not(any(feature = "debug-utils", debug_assertions)))]
+    #[inline(always)]
+    pub fn end_label(&self, _command_buffer: vk::CommandBuffer) {}
+}
@@ -0,0 +1,214 @@
+use std::path::{Path, PathBuf};
+
+use eren_common::{
+    golden::{compare_images, golden_file_name},
+    time_source::FixedTimeSource,
+};
+use image::RgbaImage;
+use thiserror::Error;
+
+use crate::{
+    asset::AssetSource,
+    offscreen::{
+        HeadlessContext, HeadlessContextInitializationError, OffscreenTarget,
+        OffscreenTargetInitializationError,
+    },
+    test_vertex_input::{
+        assets::{TestAssets, TestAssetsLoadError},
+        mesh::TestScene,
+        renderer::{OffscreenRenderError, TestOffscreenRenderer, TestRendererInitializationError},
+    },
+    validation::ValidationErrors,
+};
+
+pub const GOLDEN_WIDTH: u32 = 256;
+pub const GOLDEN_HEIGHT: u32 = 256;
+
+// (장면, MSAA 샘플 수), wgpu 크레이트와 같은 조합
+pub const GOLDEN_CASES: [(TestScene, u32); 5] = [
+    (TestScene::Quad, 1),
+    (TestScene::Cube, 1),
+    (TestScene::Cube, 4),
+    (TestScene::TexturedQuad, 1),
+    (TestScene::GltfSphere, 1),
+];
+
+// 회전하는 장면을 검사할 시점(초)
+pub const GOLDEN_TIMESTAMPS: [f32; 4] = [0.0, 0.5, 1.0, 2.5];
+
+// 채널별 허용 오차 (드라이버/소프트웨어 래스터라이저 간 차이 흡수)
+pub const DEFAULT_TOLERANCE: u8 = 8;
+
+#[derive(Debug, Error)]
+pub enum GoldenError {
+    #[error("Failed to load assets: {0}")]
+    LoadAssets(#[from] TestAssetsLoadError),
+
+    #[error("Failed to create headless context: {0}")]
+    CreateHeadlessContext(#[from] HeadlessContextInitializationError),
+
+    #[error("Failed to create offscreen target: {0}")]
+    CreateOffscreenTarget(#[from] OffscreenTargetInitializationError),
+
+    #[error("Failed to create renderer: {0}")]
+    CreateRenderer(#[from] TestRendererInitializationError),
+
+    #[error("Failed to render offscreen: {0}")]
+    Render(#[from] OffscreenRenderError),
+
+    #[error("Validation failed: {0}")]
+    Validation(#[from] ValidationErrors),
+
+    #[error("Reference image {0} does not exist, generate it with --golden --update on lavapipe")]
+    MissingReference(PathBuf),
+
+    #[error("Failed to load reference image {0}: {1}")]
+    LoadReference(PathBuf, image::ImageError),
+
+    #[error("Failed to save image {0}: {1}")]
+    SaveImage(PathBuf, image::ImageError),
+
+    #[error("Failed to create directory {0}: {1}")]
+    CreateDirectory(PathBuf, std::io::Error),
+
+    #[error("Reference image size {actual:?} does not match {expected:?}: {path}")]
+    SizeMismatch {
+        path: PathBuf,
+        actual: (u32, u32),
+        expected: (u32, u32),
+    },
+
+    #[error("{mismatched} golden image(s) did not match, see {output_dir}")]
+    Mismatch {
+        mismatched: usize,
+        output_dir: PathBuf,
+    },
+}
+
+fn save_image(image: &RgbaImage, path: &Path) -> Result<(), GoldenError> {
+    image
+        .save(path)
+        .map_err(|e| GoldenError::SaveImage(path.to_path_buf(), e))
+}
+
+fn create_dir(path: &Path) -> Result<(), GoldenError> {
+    std::fs::create_dir_all(path).map_err(|e| GoldenError::CreateDirectory(path.to_path_buf(), e))
+}
+
+// 고정된 시점들에서 각 장면을 렌더링하여 reference_dir의 기준 이미지와 비교
+// 불일치 시 output_dir에 실제 이미지와 diff 이미지를 저장, update가 true이면 기준 이미지를 새로 저장
+pub fn run_golden_tests(
+    assets: &dyn AssetSource,
+    reference_dir: &Path,
+    output_dir: &Path,
+    tolerance: u8,
+    update: bool,
+) -> Result<(), GoldenError> {
+    let mut context = HeadlessContext::new()?;
+    let target = OffscreenTarget::new(
+        context.device.clone(),
+        GOLDEN_WIDTH,
+        GOLDEN_HEIGHT,
+        &context.capabilities.debug_utils,
+    )?;
+
+    let mut mismatched = 0;
+
+    if update {
+        create_dir(reference_dir)?;
+    }
+
+    for (scene, requested_sample_count) in GOLDEN_CASES {
+        // 지원하지 않는 샘플 수로 대체되면 기준 이미지와 달라지므로 불일치로 보고됨
+        context
+            .capabilities
+            .set_requested_sample_count(requested_sample_count);
+
+        let scene_assets = pollster::block_on(TestAssets::load(assets, scene))?;
+
+        let mut renderer = TestOffscreenRenderer::new(
+            context.device.clone(),
+            context.command_pool.clone(),
+            &context.capabilities,
+            &target,
+            &scene_assets,
+            &context.pipeline_cache,
+        )?;
+
+        for secs in GOLDEN_TIMESTAMPS {
+            renderer.set_time_source(Box::new(FixedTimeSource::new(secs)));
+
+            let pixels = renderer.render(&target)?;
+            let actual = RgbaImage::from_raw(GOLDEN_WIDTH, GOLDEN_HEIGHT, pixels)
+                .expect("Offscreen pixel buffer size mismatch");
+
+            let file_name = golden_file_name(scene.name(), requested_sample_count, secs);
+            let reference_path = reference_dir.join(&file_name);
+
+            if update {
+                save_image(&actual, &reference_path)?;
+                log::info!("Updated golden image: {}", reference_path.display());
+                continue;
+            }
+
+            if !reference_path.exists() {
+                return Err(GoldenError::MissingReference(reference_path));
+            }
+
+            let expected = image::open(&reference_path)
+                .map_err(|e| GoldenError::LoadReference(reference_path.clone(), e))?
+                .to_rgba8();
+
+            if expected.dimensions() != actual.dimensions() {
+                return Err(GoldenError::SizeMismatch {
+                    path: reference_path,
+                    actual: actual.dimensions(),
+                    expected: expected.dimensions(),
+                });
+            }
+
+            let comparison = compare_images(&actual, &expected, tolerance);
+
+            if comparison.mismatched_pixels == 0 {
+                log::info!(
+                    "Golden image matched: {} (max diff {})",
+                    file_name,
+                    comparison.max_channel_diff
+                );
+                continue;
+            }
+
+            create_dir(output_dir)?;
+
+            let stem = file_name.trim_end_matches(".png");
+            let actual_path = output_dir.join(format!("{}.actual.png", stem));
+            let diff_path = output_dir.join(format!("{}.diff.png", stem));
+
+            save_image(&actual, &actual_path)?;
+            save_image(&comparison.diff_image, &diff_path)?;
+
+            log::error!(
+                "Golden image mismatch: {} ({} pixels over tolerance {}, max diff {}), diff: {}",
+                file_name,
+                comparison.mismatched_pixels,
+                tolerance,
+                comparison.max_channel_diff,
+                diff_path.display()
+            );
+
+            mismatched += 1;
+        }
+    }
+
+    // 이미지가 일치하더라도 validation 에러가 있으면 실패 (Strict 모드)
+    context.check_validation_errors()?;
+
+    if mismatched > 0 {
+        return Err(GoldenError::Mismatch {
+            mismatched,
+            output_dir: output_dir.to_path_buf(),
+        });
+    }
+
+    Ok(())
+}
@@ -1,22 +1,30 @@
 use std::sync::Arc;
 
-use ash::vk;
-use eren_vulkan_render_shared::{
-    command::CommandPool, device::Device, instance::Instance, physical_device::PhysicalDevice,
-    surface::Surface, swapchain::Swapchain,
-};
+use eren_common::size::{SurfaceResize, SurfaceSize};
 use eren_window::window::{WindowConfig, WindowEventHandler, WindowLifecycle};
-use winit::{
-    application::ApplicationHandler,
-    event::{StartCause, WindowEvent},
-    event_loop::{ActiveEventLoop, EventLoop},
-    window::{Window, WindowId},
-};
+use winit::{dpi::PhysicalSize, window::Window};
 
 #[cfg(target_os = "android")]
 use winit::platform::android::activity::AndroidApp;
 
+mod asset;
+mod attachment;
+mod capabilities;
+mod debug_utils;
+mod golden;
+mod msaa;
+mod offscreen;
+mod pipeline_cache;
+mod render_context;
+mod shader_reflection;
+mod texture;
+mod typed_buffer;
+mod validation;
+
 mod test_vertex_input {
+    pub mod assets;
+    pub mod mesh;
+    pub mod post_process;
     pub mod render_pass;
     pub mod renderer;
     pub mod subpass;
@@ -24,7 +32,10 @@ mod test_vertex_input {
     pub mod vertex;
 }
 
-use crate::test_vertex_input::renderer::TestRenderer;
+use crate::{
+    render_context::RenderContext,
+    test_vertex_input::{assets::TestAssets, mesh::TestScene},
+};
 
 pub fn init_logger() {
     #[cfg(target_arch = "wasm32")]
@@ -45,72 +56,78 @@ pub fn init_logger() {
 
     #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
     {
-        env_logger::init();
+        // 테스트에서 여러 번 호출될 수 있으므로 이미 초기화되었으면 무시
+        let _ = env_logger::try_init();
     }
 }
 
 struct TestWindowEventHandler {
     window: Arc<Window>,
-    surface: Arc<Surface>,
-    physical_device: Arc<PhysicalDevice>,
-    device: Arc<Device>,
-    command_pool: Arc<CommandPool>,
-    swapchain: Arc<Swapchain>,
-    renderer: TestRenderer,
+    size: SurfaceSize,
+    assets: TestAssets,
+    context: Option<RenderContext>,
 }
 
-fn create_swapchain(
-    surface: Arc<Surface>,
-    physical_device: Arc<PhysicalDevice>,
-    device: Arc<Device>,
-    command_pool: Arc<CommandPool>,
-    old_swapchain: Option<&Swapchain>,
-    width: u32,
-    height: u32,
-) -> (Arc<Swapchain>, TestRenderer) {
-    // 화면 크기 변경 시 swapchain 재생성
-    let swapchain = Arc::new(
-        Swapchain::new(
-            surface,
-            &physical_device,
-            device.clone(),
-            width,
-            height,
-            old_swapchain,
-        )
-        .unwrap(),
-    );
-
-    // renderer 재생성
-
-    let renderer = TestRenderer::new(
-        device,
-        swapchain.clone(),
-        &command_pool,
-        vk::Rect2D {
-            offset: vk::Offset2D::default(),
-            extent: vk::Extent2D { width, height },
-        },
-    )
-    .unwrap();
+impl TestWindowEventHandler {
+    // 새 물리 크기로 swapchain을 다시 만들고 이전/이후 extent를 기록
+    fn resize(&mut self, size: SurfaceSize, reason: &str) {
+        let resize = SurfaceResize::new(std::mem::replace(&mut self.size, size), size);
 
-    (swapchain, renderer)
-}
+        // 최소화 등으로 크기가 0이면 swapchain을 만들 수 없으므로 다음 resize까지 대기
+        let Some(extent) = resize.extent() else {
+            return;
+        };
 
-impl TestWindowEventHandler {
-    fn recreate_swapchain(&mut self, width: u32, height: u32) {
-        let (swapchain, renderer) = create_swapchain(
-            self.surface.clone(),
-            self.physical_device.clone(),
-            self.device.clone(),
-            self.command_pool.clone(),
-            Some(&self.swapchain),
-            width,
-            height,
+        let Some(context) = self.context.as_mut() else {
+            return;
+        };
+
+        let old_extent = context.extent();
+
+        if let Err(e) = context.recreate_swapchain(extent.width, extent.height) {
+            log::error!("Failed to recreate swapchain: {}", e);
+            return;
+        }
+
+        log::debug!(
+            "Swapchain recreated ({}): {}x{} (scale factor {}) -> {}x{} (scale factor {}, logical {:.1}x{:.1})",
+            reason,
+            old_extent.width,
+            old_extent.height,
+            resize.old.scale_factor,
+            extent.width,
+            extent.height,
+            resize.new.scale_factor,
+            resize.new.logical().width,
+            resize.new.logical().height
         );
+    }
 
-        self.swapchain = swapchain;
-        self.renderer = renderer;
+    // VK_ERROR_DEVICE_LOST 이후에는 instance부터 모든 리소스를 다시 만들어야 함
+    fn recreate_context(&mut self) {
+        // 같은 윈도우에 surface를 새로 만들기 전에 기존 리소스를 먼저 해제
+        self.context = None;
+
+        self.size = SurfaceSize::from_window(&self.window);
+
+        if self.size.is_empty() {
+            return;
+        }
+
+        match RenderContext::new(
+            self.window.clone(),
+            self.size.physical.width,
+            self.size.physical.height,
+            &self.assets,
+        ) {
+            Ok(context) => {
+                log::debug!("Render context recreated");
+                self.context = Some(context);
+            }
+            Err(e) => {
+                log::error!("Failed to recreate render context: {}", e);
+            }
+        }
     }
 }
 
@@ -118,68 +135,76 @@ impl WindowEventHandler for TestWindowEventHandler {
     async fn new(window: Arc<Window>) -> Self {
         log::debug!("Window created");
 
-        let instance = Arc::new(Instance::new(window.clone()).unwrap());
-        let surface = Arc::new(Surface::new(instance.clone()).unwrap());
-        let physical_device =
-            Arc::new(PhysicalDevice::new(instance.clone(), surface.clone()).unwrap());
-        let device = Arc::new(Device::new(instance.clone(), physical_device.clone()).unwrap());
-        let command_pool = Arc::new(CommandPool::new(device.clone()).unwrap());
-
-        let window_size = window.inner_size();
-        let (swapchain, renderer) = create_swapchain(
-            surface.clone(),
-            physical_device.clone(),
-            device.clone(),
-            command_pool.clone(),
-            None,
-            window_size.width,
-            window_size.height,
-        );
+        let asset_source = asset::default_asset_source().unwrap();
+        let assets = TestAssets::load(asset_source.as_ref(), TestScene::TexturedQuad)
+            .await
+            .unwrap();
+
+        let size = SurfaceSize::from_window(&window);
+        let context = RenderContext::new(
+            window.clone(),
+            size.physical.width,
+            size.physical.height,
+            &assets,
+        )
+        .unwrap();
 
         log::debug!("Renderer created");
 
-        let window_scale_factor = window.scale_factor();
-        log::debug!("Window scale factor: {}", window_scale_factor);
+        log::debug!("Window scale factor: {}", size.scale_factor);
 
         Self {
             window,
-            surface,
-            physical_device,
-            device,
-            swapchain,
-            command_pool,
-            renderer,
+            size,
+            assets,
+            context: Some(context),
         }
     }
 
     fn on_resized(&mut self, width: u32, height: u32) {
         log::debug!("Window resized: {}x{}", width, height);
-        self.recreate_swapchain(width, height);
+
+        let size = SurfaceSize::new(PhysicalSize::new(width, height), self.window.scale_factor());
+        self.resize(size, "resized");
     }
 
     fn on_scale_factor_changed(&mut self, scale_factor: f64) {
         log::debug!("Scale factor changed: {}", scale_factor);
 
-        //TODO: 테스트해보기
-        /*let window_size = self.window.inner_size();
-        self.recreate_swapchain(window_size.width, window_size.height);*/
+        // 같은 논리 크기라도 scale factor가 바뀌면 물리 크기가 달라짐
+        // uniform은 매 프레임 swapchain extent의 종횡비로만 계산되므로 scale factor로 갱신할 값은 없음
+        let size = self.size.rescaled(scale_factor);
+        self.resize(size, "scale factor changed");
     }
 
     fn on_redraw_requested(&mut self) {
         //log::debug!("Redraw requested");
 
-        let is_suboptimal = self.renderer.render().unwrap();
-
-        if is_suboptimal {
-            let window_size = self.window.inner_size();
-
-            log::debug!(
-                "Window resized (suboptimal): {}x{}",
-                window_size.width,
-                window_size.height
-            );
+        if self.size.is_empty() {
+            return;
+        }
 
-            self.recreate_swapchain(window_size.width, window_size.height);
+        // 이전에 디바이스 재생성이 실패한 경우 다시 시도
+        let Some(context) = self.context.as_mut() else {
+            self.recreate_context();
+            return;
+        };
+
+        match context.render() {
+            Ok(false) => {}
+            Ok(true) => {
+                self.resize(SurfaceSize::from_window(&self.window), "suboptimal");
+            }
+            Err(e) if e.is_out_of_date() => {
+                self.resize(SurfaceSize::from_window(&self.window), "out of date");
+            }
+            Err(e) if e.is_device_lost() => {
+                log::error!("Device lost, recreating render context: {}", e);
+                self.recreate_context();
+            }
+            Err(e) => {
+                log::error!("Failed to render: {}", e);
+            }
         }
     }
 }
@@ -190,19 +215,23 @@ impl Drop for TestWindowEventHandler {
     }
 }
 
+fn window_config() -> WindowConfig {
+    WindowConfig {
+        width: 800,
+        height: 600,
+        title: "Test Window",
+        canvas_id: None,
+    }
+}
+
 #[cfg(target_os = "android")]
 #[unsafe(no_mangle)]
 fn android_main(app: AndroidApp) {
     init_logger();
 
-    match WindowLifecycle::<TestWindowEventHandler>::new(WindowConfig {
-        width: 800,
-        height: 600,
-        title: "Test Window",
-        canvas_id: None,
-    })
-    .start_event_loop(app)
-    {
+    asset::set_android_app(app.clone());
+
+    match WindowLifecycle::<TestWindowEventHandler>::new(window_config()).start_event_loop(app) {
         Ok(_) => {}
         Err(e) => {
             log::error!("Failed to start event loop: {}", e);
@@ -215,17 +244,132 @@ fn android_main(app: AndroidApp) {
 pub extern "C" fn start_rust_app() {
     init_logger();
 
-    match WindowLifecycle::<TestWindowEventHandler>::new(WindowConfig {
-        width: 800,
-        height: 600,
-        title: "Test Window",
-        canvas_id: None,
-    })
-    .start_event_loop()
-    {
+    match WindowLifecycle::<TestWindowEventHandler>::new(window_config()).start_event_loop() {
+        Ok(_) => {}
+        Err(e) => {
+            log::error!("Failed to start event loop: {}", e);
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+pub fn start_desktop_app() {
+    init_logger();
+
+    match WindowLifecycle::<TestWindowEventHandler>::new(window_config()).start_event_loop() {
         Ok(_) => {}
         Err(e) => {
             log::error!("Failed to start event loop: {}", e);
         }
     }
 }
+
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+#[derive(Debug, thiserror::Error)]
+pub enum HeadlessRenderError {
+    #[error("Failed to create asset source: {0}")]
+    CreateAssetSource(#[from] asset::AssetLoadError),
+
+    #[error("Failed to load assets: {0}")]
+    LoadAssets(#[from] test_vertex_input::assets::TestAssetsLoadError),
+
+    #[error("Failed to create headless context: {0}")]
+    CreateHeadlessContext(#[from] offscreen::HeadlessContextInitializationError),
+
+    #[error("Failed to create offscreen target: {0}")]
+    CreateOffscreenTarget(#[from] offscreen::OffscreenTargetInitializationError),
+
+    #[error("Failed to create renderer: {0}")]
+    CreateRenderer(#[from] test_vertex_input::renderer::TestRendererInitializationError),
+
+    #[error("Failed to render offscreen: {0}")]
+    Render(#[from] test_vertex_input::renderer::OffscreenRenderError),
+
+    #[error("Failed to save image: {0}")]
+    SaveImage(#[from] image::ImageError),
+
+    #[error("Validation failed: {0}")]
+    Validation(#[from] validation::ValidationErrors),
+}
+
+// headless 렌더링에 사용된 Vulkan 기능, API 버전 상한을 낮춘 테스트에서 대체 경로를 확인할 때 사용
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+pub struct HeadlessReport {
+    pub api_version: String,
+    pub render_pass2: bool,
+    pub downgraded_features: Vec<&'static str>,
+    // validation layer를 찾지 못하면 Strict를 요청해도 false
+    pub strict_validation: bool,
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+pub fn render_headless(
+    width: u32,
+    height: u32,
+    path: &std::path::Path,
+) -> Result<HeadlessReport, HeadlessRenderError> {
+    use crate::{
+        offscreen::{HeadlessContext, OffscreenTarget, save_png},
+        test_vertex_input::renderer::TestOffscreenRenderer,
+    };
+
+    init_logger();
+
+    let asset_source = asset::default_asset_source()?;
+    let assets = pollster::block_on(TestAssets::load(
+        asset_source.as_ref(),
+        TestScene::TexturedQuad,
+    ))?;
+
+    let context = HeadlessContext::new()?;
+    let target = OffscreenTarget::new(
+        context.device.clone(),
+        width,
+        height,
+        &context.capabilities.debug_utils,
+    )?;
+
+    let mut renderer = TestOffscreenRenderer::new(
+        context.device.clone(),
+        context.command_pool.clone(),
+        &context.capabilities,
+        &target,
+        &assets,
+        &context.pipeline_cache,
+    )?;
+    let pixels = renderer.render(&target)?;
+
+    save_png(path, width, height, &pixels)?;
+    log::debug!("Headless frame saved: {}", path.display());
+
+    context.check_validation_errors()?;
+
+    Ok(HeadlessReport {
+        api_version: capabilities::format_api_version(context.capabilities.api_version),
+        render_pass2: context.capabilities.render_pass2,
+        downgraded_features: context
+            .capabilities
+            .downgrades
+            .iter()
+            .map(|downgrade| downgrade.feature)
+            .collect(),
+        strict_validation: context.validation.mode == validation::ValidationMode::Strict,
+    })
+}
+
+// 기준 이미지는 golden/, 불일치 결과는 target/golden/에 저장
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+pub fn run_golden_tests(update: bool) -> Result<(), golden::GoldenError> {
+    init_logger();
+
+    let manifest_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    // 기준 이미지가 파일 시스템의 asset 변경에 영향받지 않도록 바이너리에 포함된 asset 사용
+    golden::run_golden_tests(
+        &asset::embedded_asset_source(),
+        &manifest_dir.join("golden"),
+        &manifest_dir.join("target").join("golden"),
+        golden::DEFAULT_TOLERANCE,
+        update,
+    )
+}
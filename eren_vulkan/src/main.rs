@@ -0,0 +1,30 @@
+fn main() {
+    #[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+    {
+        let args: Vec<String> = std::env::args().collect();
+
+        match args.get(1).map(String::as_str) {
+            // cargo run -- --headless [output.png]
+            Some("--headless") => {
+                let path = args.get(2).map(String::as_str).unwrap_or("headless.png");
+
+                if let Err(e) =
+                    eren_vulkan_mobile_test::render_headless(800, 600, std::path::Path::new(path))
+                {
+                    log::error!("Failed to render headless frame: {}", e);
+                    std::process::exit(1);
+                }
+            }
+            // cargo run -- --golden [--update]
+            Some("--golden") => {
+                let update = args.get(2).is_some_and(|arg| arg == "--update");
+
+                if let Err(e) = eren_vulkan_mobile_test::run_golden_tests(update) {
+                    log::error!("Golden image test failed: {}", e);
+                    std::process::exit(1);
+                }
+            }
+            _ => eren_vulkan_mobile_test::start_desktop_app(),
+        }
+    }
+}
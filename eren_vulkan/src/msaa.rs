@@ -0,0 +1,47 @@
+use ash::vk;
+
+// 요청할 MSAA 샘플 수 (1, 2, 4 중 하나, 예: EREN_MSAA_SAMPLES=1)
+pub const SAMPLE_COUNT_ENV: &str = "EREN_MSAA_SAMPLES";
+
+pub const DEFAULT_SAMPLE_COUNT: u32 = 4;
+
+// 높은 샘플 수부터 차례로 지원 여부를 확인
+const SAMPLE_COUNTS: [(u32, vk::SampleCountFlags); 3] = [
+    (4, vk::SampleCountFlags::TYPE_4),
+    (2, vk::SampleCountFlags::TYPE_2),
+    (1, vk::SampleCountFlags::TYPE_1),
+];
+
+pub fn requested_sample_count() -> u32 {
+    let Ok(value) = std::env::var(SAMPLE_COUNT_ENV) else {
+        return DEFAULT_SAMPLE_COUNT;
+    };
+
+    match value.trim().parse() {
+        Ok(count) if SAMPLE_COUNTS.iter().any(|&(n, _)| n == count) => count,
+        _ => {
+            log::warn!(
+                "Invalid {} value: {:?}, using {}",
+                SAMPLE_COUNT_ENV,
+                value,
+                DEFAULT_SAMPLE_COUNT
+            );
+            DEFAULT_SAMPLE_COUNT
+        }
+    }
+}
+
+// supported에 포함된 샘플 수 중 requested 이하인 가장 큰 값
+pub fn select_sample_count(
+    requested: u32,
+    supported: vk::SampleCountFlags,
+) -> vk::SampleCountFlags {
+    SAMPLE_COUNTS
+        .iter()
+        .find(|&&(count, flags)| count <= requested && supported.contains(flags))
+        .map_or(vk::SampleCountFlags::TYPE_1, |&(_, flags)| flags)
+}
+
+pub fn sample_count_value(samples: vk::SampleCountFlags) -> u32 {
+    samples.as_raw()
+}
@@ -0,0 +1,232 @@
+use std::{path::Path, sync::Arc};
+
+use ash::vk;
+use eren_vulkan_render_shared::{
+    command::{CommandPool, CommandPoolCreationError},
+    device::{
+        BufferWithMemoryCreationError, CopyCommandBufferError, Device, DeviceCreationError,
+        ImageViewCreationError, ImageWithMemoryCreationError, MapMemoryError,
+    },
+    instance::{Instance, InstanceCreationError},
+    physical_device::{PhysicalDevice, PhysicalDeviceSelectionError},
+};
+use thiserror::Error;
+
+use crate::{
+    capabilities::{self, VulkanCapabilities},
+    debug_utils::DebugUtils,
+    pipeline_cache::{PipelineCache, PipelineCacheCreationError},
+    validation::{DebugMessenger, DebugMessengerCreationError, Validation, ValidationErrors},
+};
+
+pub const OFFSCREEN_FORMAT: vk::Format = vk::Format::R8G8B8A8_SRGB;
+
+const BYTES_PER_PIXEL: u32 = 4;
+
+#[derive(Debug, Error)]
+pub enum HeadlessContextInitializationError {
+    #[error("Failed to create instance: {0}")]
+    CreateInstance(#[from] InstanceCreationError),
+
+    #[error("Failed to create debug messenger: {0}")]
+    CreateDebugMessenger(#[from] DebugMessengerCreationError),
+
+    #[error("Failed to select physical device: {0}")]
+    SelectPhysicalDevice(#[from] PhysicalDeviceSelectionError),
+
+    #[error("Failed to create device: {0}")]
+    CreateDevice(#[from] DeviceCreationError),
+
+    #[error("Failed to create command pool: {0}")]
+    CreateCommandPool(#[from] CommandPoolCreationError),
+
+    #[error("Failed to create pipeline cache: {0}")]
+    CreatePipelineCache(#[from] PipelineCacheCreationError),
+}
+
+// Surface/Swapchain 없이 사용하는 Vulkan 디바이스 (lavapipe 등)
+pub struct HeadlessContext {
+    pub device: Arc<Device>,
+    pub command_pool: Arc<CommandPool>,
+    pub capabilities: VulkanCapabilities,
+    pub pipeline_cache: PipelineCache,
+    pub validation: Validation,
+    debug_messenger: Option<DebugMessenger>,
+}
+
+impl HeadlessContext {
+    pub fn new() -> Result<Self, HeadlessContextInitializationError> {
+        let validation = Validation::from_env();
+        let instance = Arc::new(Instance::new_headless_with_layers(
+            capabilities::max_api_version(),
+            &validation.layers,
+            &validation.extensions,
+        )?);
+        let debug_messenger = validation.create_messenger(&instance)?;
+        let physical_device = Arc::new(PhysicalDevice::new_headless(instance.clone())?);
+
+        let device = Arc::new(Device::new(instance.clone(), physical_device.clone())?);
+        let capabilities = VulkanCapabilities::detect(
+            &instance,
+            &physical_device,
+            &device,
+            &validation.extensions,
+        );
+        capabilities.log_report();
+
+        let command_pool = Arc::new(CommandPool::new(device.clone())?);
+        let pipeline_cache = PipelineCache::new(device.clone(), &physical_device)?;
+
+        Ok(Self {
+            device,
+            command_pool,
+            capabilities,
+            pipeline_cache,
+            validation,
+            debug_messenger,
+        })
+    }
+
+    // Strict 모드에서 이 컨텍스트의 messenger가 받은 validation 에러가 있으면 실패
+    pub fn check_validation_errors(&self) -> Result<(), ValidationErrors> {
+        self.validation.check_errors(self.debug_messenger.as_ref())
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum OffscreenTargetInitializationError {
+    #[error("Failed to create image with memory: {0}")]
+    ImageWithMemory(#[from] ImageWithMemoryCreationError),
+
+    #[error("Failed to create image view: {0}")]
+    ImageView(#[from] ImageViewCreationError),
+
+    #[error("Failed to create readback buffer: {0}")]
+    ReadbackBuffer(#[from] BufferWithMemoryCreationError),
+}
+
+#[derive(Debug, Error)]
+pub enum ReadPixelsError {
+    #[error("Failed to copy image to buffer: {0}")]
+    CopyImageToBuffer(#[from] CopyCommandBufferError),
+
+    #[error("Failed to map memory: {0}")]
+    MapMemory(#[from] MapMemoryError),
+}
+
+// Swapchain 이미지 대신 렌더링 대상으로 사용하는 device-local 이미지와 CPU 읽기용 버퍼
+pub struct OffscreenTarget {
+    device: Arc<Device>,
+    pub width: u32,
+    pub height: u32,
+    image: vk::Image,
+    image_memory: vk::DeviceMemory,
+    image_view: vk::ImageView,
+    readback_buffer: vk::Buffer,
+    readback_memory: vk::DeviceMemory,
+}
+
+impl OffscreenTarget {
+    pub fn new(
+        device: Arc<Device>,
+        width: u32,
+        height: u32,
+        debug_utils: &DebugUtils,
+    ) -> Result<Self, OffscreenTargetInitializationError> {
+        let (image, image_memory) = device.create_image_with_memory(
+            width,
+            height,
+            OFFSCREEN_FORMAT,
+            vk::ImageUsageFlags::COLOR_ATTACHMENT | vk::ImageUsageFlags::TRANSFER_SRC,
+            vk::MemoryPropertyFlags::DEVICE_LOCAL,
+        )?;
+        debug_utils.set_name(image, "OffscreenTarget image");
+
+        let image_view =
+            device.create_image_view(image, OFFSCREEN_FORMAT, vk::ImageAspectFlags::COLOR)?;
+
+        let (readback_buffer, readback_memory) = device.create_buffer_with_memory(
+            (width * height * BYTES_PER_PIXEL) as vk::DeviceSize,
+            vk::BufferUsageFlags::TRANSFER_DST,
+            vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT,
+        )?;
+        debug_utils.set_name(readback_buffer, "OffscreenTarget readback buffer");
+
+        Ok(Self {
+            device,
+            width,
+            height,
+            image,
+            image_memory,
+            image_view,
+            readback_buffer,
+            readback_memory,
+        })
+    }
+
+    pub fn extent(&self) -> vk::Extent2D {
+        vk::Extent2D {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    // 렌더 패스가 끝나면 바로 복사할 수 있도록 TRANSFER_SRC_OPTIMAL로 전환
+    pub fn get_color_attachment_desc(&self) -> vk::AttachmentDescription2<'static> {
+        vk::AttachmentDescription2::default()
+            .format(OFFSCREEN_FORMAT)
+            .samples(vk::SampleCountFlags::TYPE_1)
+            .load_op(vk::AttachmentLoadOp::CLEAR)
+            .store_op(vk::AttachmentStoreOp::STORE)
+            .stencil_load_op(vk::AttachmentLoadOp::DONT_CARE)
+            .stencil_store_op(vk::AttachmentStoreOp::DONT_CARE)
+            .initial_layout(vk::ImageLayout::UNDEFINED)
+            .final_layout(vk::ImageLayout::TRANSFER_SRC_OPTIMAL)
+    }
+
+    pub fn image_view(&self) -> vk::ImageView {
+        self.image_view
+    }
+
+    // 렌더링이 끝난 이미지를 버퍼로 복사한 후 RGBA8 픽셀을 반환
+    pub fn read_pixels(&self, command_pool: &CommandPool) -> Result<Vec<u8>, ReadPixelsError> {
+        command_pool.copy_image_to_buffer(
+            self.image,
+            self.readback_buffer,
+            self.width,
+            self.height,
+        )?;
+
+        let size = (self.width * self.height * BYTES_PER_PIXEL) as usize;
+        let data_ptr = self
+            .device
+            .map_memory(self.readback_memory, size as vk::DeviceSize)?;
+
+        let pixels = unsafe { std::slice::from_raw_parts(data_ptr as *const u8, size) }.to_vec();
+
+        self.device.unmap_memory(self.readback_memory);
+
+        Ok(pixels)
+    }
+}
+
+impl Drop for OffscreenTarget {
+    fn drop(&mut self) {
+        self.device.wait_idle();
+
+        self.device
+            .destroy_buffer_with_memory(self.readback_buffer, self.readback_memory);
+        self.device.destroy_image_view(self.image_view);
+        self.device
+            .destroy_image_with_memory(self.image, self.image_memory);
+    }
+}
+
+pub fn save_png(
+    path: &Path,
+    width: u32,
+    height: u32,
+    pixels: &[u8],
+) -> Result<(), image::ImageError> {
+    image::save_buffer(path, pixels, width, height, image::ExtendedColorType::Rgba8)
+}
@@ -0,0 +1,179 @@
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use ash::vk;
+use eren_common::pipeline_cache::{self, CacheFileLoad, read_cache_file, write_cache_file};
+use eren_vulkan_render_shared::{device::Device, physical_device::PhysicalDevice};
+use thiserror::Error;
+
+// VkPipelineCacheHeaderVersionOne 크기 (headerSize, headerVersion, vendorID, deviceID, UUID)
+const CACHE_HEADER_SIZE: usize = 16 + vk::UUID_SIZE;
+
+#[derive(Debug, Error)]
+pub enum PipelineCacheCreationError {
+    #[error("Failed to create pipeline cache: {0}")]
+    CreatePipelineCache(#[from] vk::Result),
+}
+
+// 드라이버에 넘기기 전에 캐시 헤더가 현재 디바이스의 것인지 확인
+// 잘못된 데이터를 넘기면 일부 드라이버가 크래시하므로 식별 문자열과 별개로 검사
+fn validate_cache_header(data: &[u8], properties: &vk::PhysicalDeviceProperties) -> bool {
+    let Some(header) = data.get(..CACHE_HEADER_SIZE) else {
+        return false;
+    };
+
+    // 헤더 값은 호스트 바이트 순서로 저장됨
+    let read_u32 = |offset: usize| {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&header[offset..offset + 4]);
+        u32::from_ne_bytes(bytes)
+    };
+
+    read_u32(0) as usize >= CACHE_HEADER_SIZE
+        && read_u32(4) == vk::PipelineCacheHeaderVersion::ONE.as_raw() as u32
+        && read_u32(8) == properties.vendor_id
+        && read_u32(12) == properties.device_id
+        && header[16..] == properties.pipeline_cache_uuid
+}
+
+// Android는 asset과 같은 AndroidApp의 내부 저장소를 사용
+#[cfg(target_os = "android")]
+fn default_cache_dir() -> Option<PathBuf> {
+    pipeline_cache::default_cache_dir(crate::asset::android_app()?)
+}
+
+#[cfg(not(target_os = "android"))]
+fn default_cache_dir() -> Option<PathBuf> {
+    pipeline_cache::default_cache_dir(env!("CARGO_PKG_NAME"))
+}
+
+// 디바이스/드라이버별로 파일에 저장하는 VkPipelineCache
+pub struct PipelineCache {
+    device: Arc<Device>,
+    cache: vk::PipelineCache,
+    path: Option<PathBuf>,
+    identity: String,
+    // 마지막으로 읽거나 저장한 데이터, 바뀌지 않았으면 다시 쓰지 않음
+    saved: Mutex<Vec<u8>>,
+}
+
+impl PipelineCache {
+    pub fn new(
+        device: Arc<Device>,
+        physical_device: &PhysicalDevice,
+    ) -> Result<Self, PipelineCacheCreationError> {
+        let properties = physical_device.properties();
+        let uuid: String = properties
+            .pipeline_cache_uuid
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        let identity = format!(
+            "{:04x}|{:04x}|{:08x}|{}",
+            properties.vendor_id, properties.device_id, properties.driver_version, uuid
+        );
+
+        let path = default_cache_dir().map(|dir| {
+            dir.join(format!(
+                "vulkan_{:04x}_{:04x}.bin",
+                properties.vendor_id, properties.device_id
+            ))
+        });
+
+        let data = match &path {
+            Some(path) => match read_cache_file(path, &identity) {
+                CacheFileLoad::Hit(data) if !validate_cache_header(&data, properties) => {
+                    log::info!(
+                        "Pipeline cache invalidated (header mismatch): {}",
+                        path.display()
+                    );
+                    let _ = std::fs::remove_file(path);
+                    None
+                }
+                CacheFileLoad::Hit(data) => {
+                    log::info!(
+                        "Pipeline cache hit: {} ({} bytes)",
+                        path.display(),
+                        data.len()
+                    );
+                    Some(data)
+                }
+                CacheFileLoad::Miss => {
+                    log::info!("Pipeline cache miss: {}", path.display());
+                    None
+                }
+                CacheFileLoad::Invalidated(reason) => {
+                    log::info!(
+                        "Pipeline cache invalidated ({}): {}",
+                        reason,
+                        path.display()
+                    );
+                    let _ = std::fs::remove_file(path);
+                    None
+                }
+            },
+            None => {
+                log::warn!("No directory for pipeline cache, cache will not be saved");
+                None
+            }
+        };
+
+        let info =
+            vk::PipelineCacheCreateInfo::default().initial_data(data.as_deref().unwrap_or(&[]));
+        let cache = unsafe { device.raw().create_pipeline_cache(&info, None) }?;
+
+        Ok(Self {
+            device,
+            cache,
+            path,
+            identity,
+            saved: Mutex::new(data.unwrap_or_default()),
+        })
+    }
+
+    pub fn handle(&self) -> vk::PipelineCache {
+        self.cache
+    }
+
+    // 파이프라인을 만든 직후 호출 (Android 앱은 종료 이벤트 없이 끝날 수 있음)
+    pub fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        let data = match unsafe { self.device.raw().get_pipeline_cache_data(self.cache) } {
+            Ok(data) => data,
+            Err(e) => {
+                log::warn!("Failed to get pipeline cache data: {}", e);
+                return;
+            }
+        };
+
+        let mut saved = self.saved.lock().unwrap();
+        if *saved == data {
+            return;
+        }
+
+        match write_cache_file(path, &self.identity, &data) {
+            Ok(()) => {
+                log::debug!(
+                    "Pipeline cache saved: {} ({} bytes)",
+                    path.display(),
+                    data.len()
+                );
+                *saved = data;
+            }
+            Err(e) => log::warn!("Failed to save pipeline cache {}: {}", path.display(), e),
+        }
+    }
+}
+
+impl Drop for PipelineCache {
+    fn drop(&mut self) {
+        unsafe {
+            self.device.raw().destroy_pipeline_cache(self.cache, None);
+        }
+    }
+}
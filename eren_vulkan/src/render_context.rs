@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use ash::vk;
+use eren_vulkan_render_shared::{
+    command::{CommandPool, CommandPoolCreationError},
+    device::{Device, DeviceCreationError},
+    instance::{Instance, InstanceCreationError},
+    physical_device::{PhysicalDevice, PhysicalDeviceSelectionError},
+    surface::{Surface, SurfaceCreationError},
+    swapchain::{Swapchain, SwapchainCreationError},
+};
+use thiserror::Error;
+use winit::window::Window;
+
+use crate::{
+    capabilities::{self, VulkanCapabilities},
+    pipeline_cache::{PipelineCache, PipelineCacheCreationError},
+    test_vertex_input::{
+        assets::TestAssets,
+        renderer::{RenderError, TestRenderer, TestRendererInitializationError},
+    },
+    validation::{DebugMessenger, DebugMessengerCreationError, Validation},
+};
+
+#[derive(Debug, Error)]
+pub enum RenderContextInitializationError {
+    #[error("Failed to create instance: {0}")]
+    CreateInstance(#[from] InstanceCreationError),
+
+    #[error("Failed to create debug messenger: {0}")]
+    CreateDebugMessenger(#[from] DebugMessengerCreationError),
+
+    #[error("Failed to create surface: {0}")]
+    CreateSurface(#[from] SurfaceCreationError),
+
+    #[error("Failed to select physical device: {0}")]
+    SelectPhysicalDevice(#[from] PhysicalDeviceSelectionError),
+
+    #[error("Failed to create device: {0}")]
+    CreateDevice(#[from] DeviceCreationError),
+
+    #[error("Failed to create command pool: {0}")]
+    CreateCommandPool(#[from] CommandPoolCreationError),
+
+    #[error("Failed to create pipeline cache: {0}")]
+    CreatePipelineCache(#[from] PipelineCacheCreationError),
+
+    #[error("Failed to create swapchain: {0}")]
+    CreateSwapchain(#[from] SwapchainCreationError),
+
+    #[error("Failed to create renderer: {0}")]
+    CreateRenderer(#[from] TestRendererInitializationError),
+}
+
+fn render_area(width: u32, height: u32) -> vk::Rect2D {
+    vk::Rect2D {
+        offset: vk::Offset2D::default(),
+        extent: vk::Extent2D { width, height },
+    }
+}
+
+// 윈도우에 렌더링하는 데 필요한 모든 Vulkan 리소스
+// 필드 선언 순서대로 해제되므로 renderer부터 instance 순으로 선언
+pub struct RenderContext {
+    renderer: TestRenderer,
+    swapchain: Arc<Swapchain>,
+    _pipeline_cache: PipelineCache,
+    command_pool: Arc<CommandPool>,
+    device: Arc<Device>,
+    physical_device: Arc<PhysicalDevice>,
+    surface: Arc<Surface>,
+    _debug_messenger: Option<DebugMessenger>,
+    _instance: Arc<Instance>,
+}
+
+impl RenderContext {
+    pub fn new(
+        window: Arc<Window>,
+        width: u32,
+        height: u32,
+        assets: &TestAssets,
+    ) -> Result<Self, RenderContextInitializationError> {
+        let validation = Validation::from_env();
+        let instance = Arc::new(Instance::with_layers(
+            window,
+            capabilities::max_api_version(),
+            &validation.layers,
+            &validation.extensions,
+        )?);
+        let debug_messenger = validation.create_messenger(&instance)?;
+        let surface = Arc::new(Surface::new(instance.clone())?);
+        let physical_device = Arc::new(PhysicalDevice::new(instance.clone(), surface.clone())?);
+
+        let device = Arc::new(Device::new(instance.clone(), physical_device.clone())?);
+        let capabilities = VulkanCapabilities::detect(
+            &instance,
+            &physical_device,
+            &device,
+            &validation.extensions,
+        );
+        capabilities.log_report();
+        let command_pool = Arc::new(CommandPool::new(device.clone())?);
+        let pipeline_cache = PipelineCache::new(device.clone(), &physical_device)?;
+
+        let swapchain = Arc::new(Swapchain::new(
+            surface.clone(),
+            &physical_device,
+            device.clone(),
+            width,
+            height,
+            None,
+        )?);
+
+        let renderer = TestRenderer::new(
+            device.clone(),
+            swapchain.clone(),
+            assets,
+            &command_pool,
+            &capabilities,
+            render_area(width, height),
+            &pipeline_cache,
+        )?;
+
+        Ok(Self {
+            renderer,
+            swapchain,
+            _pipeline_cache: pipeline_cache,
+            command_pool,
+            device,
+            physical_device,
+            surface,
+            _debug_messenger: debug_messenger,
+            _instance: instance,
+        })
+    }
+
+    pub fn recreate_swapchain(
+        &mut self,
+        width: u32,
+        height: u32,
+    ) -> Result<(), RenderContextInitializationError> {
+        // 화면 크기 변경 시 swapchain 재생성
+        let swapchain = Arc::new(Swapchain::new(
+            self.surface.clone(),
+            &self.physical_device,
+            self.device.clone(),
+            width,
+            height,
+            Some(&self.swapchain),
+        )?);
+
+        // 버퍼, descriptor set, 파이프라인은 유지하고 swapchain에 묶인 리소스만 갱신
+        self.renderer.on_swapchain_recreated(
+            swapchain.clone(),
+            &self.command_pool,
+            render_area(width, height),
+        )?;
+
+        self.swapchain = swapchain;
+
+        Ok(())
+    }
+
+    pub fn extent(&self) -> vk::Extent2D {
+        vk::Extent2D {
+            width: self.swapchain.window_width,
+            height: self.swapchain.window_height,
+        }
+    }
+
+    pub fn render(&mut self) -> Result<bool, RenderError> {
+        self.renderer.render()
+    }
+}
@@ -0,0 +1,1028 @@
+use std::collections::{BTreeMap, HashMap};
+
+use ash::vk;
+use thiserror::Error;
+
+// SPIR-V 명세의 opcode, decoration, storage class 값 중 reflection에 필요한 것만 사용
+const SPIRV_MAGIC: u32 = 0x0723_0203;
+
+const OP_ENTRY_POINT: u32 = 15;
+const OP_TYPE_BOOL: u32 = 20;
+const OP_TYPE_INT: u32 = 21;
+const OP_TYPE_FLOAT: u32 = 22;
+const OP_TYPE_VECTOR: u32 = 23;
+const OP_TYPE_MATRIX: u32 = 24;
+const OP_TYPE_IMAGE: u32 = 25;
+const OP_TYPE_SAMPLER: u32 = 26;
+const OP_TYPE_SAMPLED_IMAGE: u32 = 27;
+const OP_TYPE_ARRAY: u32 = 28;
+const OP_TYPE_RUNTIME_ARRAY: u32 = 29;
+const OP_TYPE_STRUCT: u32 = 30;
+const OP_TYPE_POINTER: u32 = 32;
+const OP_CONSTANT: u32 = 43;
+const OP_VARIABLE: u32 = 59;
+const OP_DECORATE: u32 = 71;
+const OP_MEMBER_DECORATE: u32 = 72;
+
+const DECORATION_BLOCK: u32 = 2;
+const DECORATION_BUFFER_BLOCK: u32 = 3;
+const DECORATION_ARRAY_STRIDE: u32 = 6;
+const DECORATION_MATRIX_STRIDE: u32 = 7;
+const DECORATION_BUILT_IN: u32 = 11;
+const DECORATION_LOCATION: u32 = 30;
+const DECORATION_BINDING: u32 = 33;
+const DECORATION_DESCRIPTOR_SET: u32 = 34;
+const DECORATION_OFFSET: u32 = 35;
+
+const STORAGE_CLASS_UNIFORM_CONSTANT: u32 = 0;
+const STORAGE_CLASS_INPUT: u32 = 1;
+const STORAGE_CLASS_UNIFORM: u32 = 2;
+const STORAGE_CLASS_PUSH_CONSTANT: u32 = 9;
+const STORAGE_CLASS_STORAGE_BUFFER: u32 = 12;
+
+const EXECUTION_MODEL_VERTEX: u32 = 0;
+const EXECUTION_MODEL_FRAGMENT: u32 = 4;
+const EXECUTION_MODEL_GL_COMPUTE: u32 = 5;
+
+const DIM_BUFFER: u32 = 5;
+const DIM_SUBPASS_DATA: u32 = 6;
+
+#[derive(Debug, Error)]
+pub enum ShaderReflectionError {
+    #[error("Invalid SPIR-V: {0}")]
+    InvalidSpirv(&'static str),
+
+    #[error("SPIR-V has no entry point")]
+    MissingEntryPoint,
+
+    #[error("Unsupported execution model: {0}")]
+    UnsupportedExecutionModel(u32),
+
+    #[error("Unsupported type for vertex input at location {0}")]
+    UnsupportedVertexInputType(u32),
+
+    #[error("Unsupported type for descriptor at set {set}, binding {binding}")]
+    UnsupportedDescriptorType { set: u32, binding: u32 },
+
+    #[error(
+        "Descriptor at set {set}, binding {binding} is {first:?} in one stage and {second:?} in another"
+    )]
+    DescriptorTypeConflict {
+        set: u32,
+        binding: u32,
+        first: vk::DescriptorType,
+        second: vk::DescriptorType,
+    },
+
+    #[error("Shader reads vertex input location {0}, but the vertex layout does not provide it")]
+    MissingVertexAttribute(u32),
+
+    #[error(
+        "Vertex input location {location} is {shader:?} in the shader, but {vertex:?} in the vertex layout"
+    )]
+    VertexAttributeFormatMismatch {
+        location: u32,
+        shader: vk::Format,
+        vertex: vk::Format,
+    },
+
+    #[error("Shader has no uniform buffer at set {set}, binding {binding}")]
+    MissingUniformBuffer { set: u32, binding: u32 },
+
+    #[error(
+        "Uniform buffer at set {set}, binding {binding} is {shader} bytes in the shader, but {rust} bytes in Rust"
+    )]
+    UniformBufferSizeMismatch {
+        set: u32,
+        binding: u32,
+        shader: u32,
+        rust: u32,
+    },
+
+    #[error(
+        "Uniform buffer at set {set}, binding {binding} has {shader} members in the shader, but {rust} fields in Rust"
+    )]
+    UniformBufferMemberCountMismatch {
+        set: u32,
+        binding: u32,
+        shader: usize,
+        rust: usize,
+    },
+
+    #[error(
+        "Uniform buffer member {member} at set {set}, binding {binding} is at offset {shader} in the shader, but {rust} in Rust"
+    )]
+    UniformBufferMemberOffsetMismatch {
+        set: u32,
+        binding: u32,
+        member: usize,
+        shader: u32,
+        rust: u32,
+    },
+}
+
+// uniform buffer로 사용하는 Rust 구조체의 필드 offset, 셰이더 블록의 멤버 순서와 같아야 함
+// 크기가 같아도 필드 순서나 패딩이 다르면 찾아낼 수 있도록 offset_of!로 채움
+pub trait UniformLayout {
+    const MEMBER_OFFSETS: &'static [u32];
+}
+
+// uniform/storage buffer 블록의 크기와 멤버별 Offset decoration
+#[derive(Clone, Debug)]
+pub struct BlockLayout {
+    pub size: u32,
+    pub member_offsets: Vec<u32>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ReflectedBinding {
+    pub set: u32,
+    pub binding: u32,
+    pub descriptor_type: vk::DescriptorType,
+    pub count: u32,
+    pub stages: vk::ShaderStageFlags,
+    // uniform/storage buffer의 블록 레이아웃
+    pub block: Option<BlockLayout>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ReflectedVertexInput {
+    pub location: u32,
+    pub format: vk::Format,
+}
+
+// 셰이더 모듈 하나의 reflection 결과
+pub struct ShaderReflection {
+    pub stage: vk::ShaderStageFlags,
+    pub bindings: Vec<ReflectedBinding>,
+    pub push_constant_size: Option<u32>,
+    // vertex 셰이더가 아니면 비어 있음
+    pub vertex_inputs: Vec<ReflectedVertexInput>,
+}
+
+enum SpirvType {
+    Bool,
+    Int { width: u32, signed: bool },
+    Float { width: u32 },
+    Vector { component: u32, count: u32 },
+    Matrix { column: u32, count: u32 },
+    Image { dim: u32, sampled: u32 },
+    Sampler,
+    SampledImage,
+    Array { element: u32, length: u32 },
+    // 크기를 알 수 없으므로 블록 크기 계산에서 0으로 취급
+    RuntimeArray,
+    Struct { members: Vec<u32> },
+    Pointer { pointee: u32 },
+}
+
+#[derive(Default)]
+struct Decorations {
+    block: bool,
+    buffer_block: bool,
+    built_in: bool,
+    location: Option<u32>,
+    binding: Option<u32>,
+    set: Option<u32>,
+    array_stride: Option<u32>,
+}
+
+#[derive(Default)]
+struct MemberDecorations {
+    offset: Option<u32>,
+    matrix_stride: Option<u32>,
+}
+
+#[derive(Default)]
+struct Module {
+    entry_point: Option<(u32, Vec<u32>)>,
+    types: HashMap<u32, SpirvType>,
+    constants: HashMap<u32, u32>,
+    // (pointer type, storage class)
+    variables: BTreeMap<u32, (u32, u32)>,
+    decorations: HashMap<u32, Decorations>,
+    member_decorations: HashMap<(u32, u32), MemberDecorations>,
+}
+
+impl ShaderReflection {
+    pub fn new(spirv: &[u8]) -> Result<Self, ShaderReflectionError> {
+        let module = parse_module(spirv)?;
+
+        let (execution_model, interface) = module
+            .entry_point
+            .as_ref()
+            .ok_or(ShaderReflectionError::MissingEntryPoint)?;
+
+        let stage = match *execution_model {
+            EXECUTION_MODEL_VERTEX => vk::ShaderStageFlags::VERTEX,
+            EXECUTION_MODEL_FRAGMENT => vk::ShaderStageFlags::FRAGMENT,
+            EXECUTION_MODEL_GL_COMPUTE => vk::ShaderStageFlags::COMPUTE,
+            model => return Err(ShaderReflectionError::UnsupportedExecutionModel(model)),
+        };
+
+        let mut bindings = Vec::new();
+        let mut push_constant_size = None;
+        let mut vertex_inputs = Vec::new();
+
+        for (&id, &(pointer_type, storage_class)) in &module.variables {
+            let Some(SpirvType::Pointer { pointee }) = module.types.get(&pointer_type) else {
+                return Err(ShaderReflectionError::InvalidSpirv(
+                    "variable type is not a pointer",
+                ));
+            };
+            let decorations = module.decorations.get(&id);
+
+            match storage_class {
+                STORAGE_CLASS_UNIFORM_CONSTANT
+                | STORAGE_CLASS_UNIFORM
+                | STORAGE_CLASS_STORAGE_BUFFER => {
+                    let (Some(set), Some(binding)) = (
+                        decorations.and_then(|d| d.set),
+                        decorations.and_then(|d| d.binding),
+                    ) else {
+                        continue;
+                    };
+
+                    let (descriptor_type, count, block) = module
+                        .descriptor_type(*pointee, storage_class)
+                        .ok_or(ShaderReflectionError::UnsupportedDescriptorType { set, binding })?;
+
+                    bindings.push(ReflectedBinding {
+                        set,
+                        binding,
+                        descriptor_type,
+                        count,
+                        stages: stage,
+                        block,
+                    });
+                }
+                STORAGE_CLASS_PUSH_CONSTANT => {
+                    push_constant_size = Some(module.size_of(*pointee));
+                }
+                STORAGE_CLASS_INPUT if stage == vk::ShaderStageFlags::VERTEX => {
+                    // SPIR-V 1.4 미만에서는 interface에 입출력 변수만 들어 있음
+                    if !interface.contains(&id) || decorations.is_some_and(|d| d.built_in) {
+                        continue;
+                    }
+
+                    let Some(location) = decorations.and_then(|d| d.location) else {
+                        continue;
+                    };
+
+                    let format = module
+                        .vertex_format(*pointee)
+                        .ok_or(ShaderReflectionError::UnsupportedVertexInputType(location))?;
+
+                    vertex_inputs.push(ReflectedVertexInput { location, format });
+                }
+                _ => {}
+            }
+        }
+
+        bindings.sort_by_key(|b| (b.set, b.binding));
+        vertex_inputs.sort_by_key(|input| input.location);
+
+        Ok(Self {
+            stage,
+            bindings,
+            push_constant_size,
+            vertex_inputs,
+        })
+    }
+}
+
+// 파이프라인을 구성하는 모든 stage의 reflection을 합친 결과
+pub struct PipelineReflection {
+    bindings: Vec<ReflectedBinding>,
+    push_constant_stages: vk::ShaderStageFlags,
+    push_constant_size: u32,
+    vertex_inputs: Vec<ReflectedVertexInput>,
+}
+
+impl PipelineReflection {
+    pub fn new(shaders: &[&[u8]]) -> Result<Self, ShaderReflectionError> {
+        let mut bindings: Vec<ReflectedBinding> = Vec::new();
+        let mut push_constant_stages = vk::ShaderStageFlags::empty();
+        let mut push_constant_size = 0;
+        let mut vertex_inputs = Vec::new();
+
+        for spirv in shaders {
+            let shader = ShaderReflection::new(spirv)?;
+
+            for binding in shader.bindings {
+                match bindings
+                    .iter_mut()
+                    .find(|b| b.set == binding.set && b.binding == binding.binding)
+                {
+                    Some(existing) if existing.descriptor_type != binding.descriptor_type => {
+                        return Err(ShaderReflectionError::DescriptorTypeConflict {
+                            set: binding.set,
+                            binding: binding.binding,
+                            first: existing.descriptor_type,
+                            second: binding.descriptor_type,
+                        });
+                    }
+                    Some(existing) => {
+                        existing.stages |= binding.stages;
+                        existing.count = existing.count.max(binding.count);
+                        if existing.block.as_ref().map(|b| b.size)
+                            < binding.block.as_ref().map(|b| b.size)
+                        {
+                            existing.block = binding.block;
+                        }
+                    }
+                    None => bindings.push(binding),
+                }
+            }
+
+            if let Some(size) = shader.push_constant_size {
+                push_constant_stages |= shader.stage;
+                push_constant_size = push_constant_size.max(size);
+            }
+
+            if shader.stage == vk::ShaderStageFlags::VERTEX {
+                vertex_inputs = shader.vertex_inputs;
+            }
+        }
+
+        bindings.sort_by_key(|b| (b.set, b.binding));
+
+        Ok(Self {
+            bindings,
+            push_constant_stages,
+            push_constant_size,
+            vertex_inputs,
+        })
+    }
+
+    pub fn descriptor_set_layout_bindings(
+        &self,
+        set: u32,
+    ) -> Vec<vk::DescriptorSetLayoutBinding<'static>> {
+        self.bindings
+            .iter()
+            .filter(|b| b.set == set)
+            .map(|b| {
+                vk::DescriptorSetLayoutBinding::default()
+                    .binding(b.binding)
+                    .descriptor_type(b.descriptor_type)
+                    .descriptor_count(b.count)
+                    .stage_flags(b.stages)
+            })
+            .collect()
+    }
+
+    // set 하나를 set_count개 할당할 수 있는 descriptor pool 크기
+    pub fn descriptor_pool_sizes(&self, set: u32, set_count: u32) -> Vec<vk::DescriptorPoolSize> {
+        let mut pool_sizes: Vec<vk::DescriptorPoolSize> = Vec::new();
+
+        for b in self.bindings.iter().filter(|b| b.set == set) {
+            match pool_sizes
+                .iter_mut()
+                .find(|size| size.ty == b.descriptor_type)
+            {
+                Some(size) => size.descriptor_count += b.count * set_count,
+                None => pool_sizes.push(vk::DescriptorPoolSize {
+                    ty: b.descriptor_type,
+                    descriptor_count: b.count * set_count,
+                }),
+            }
+        }
+
+        pool_sizes
+    }
+
+    pub fn push_constant_ranges(&self) -> Vec<vk::PushConstantRange> {
+        if self.push_constant_stages.is_empty() {
+            return Vec::new();
+        }
+
+        vec![vk::PushConstantRange {
+            stage_flags: self.push_constant_stages,
+            offset: 0,
+            size: self.push_constant_size,
+        }]
+    }
+
+    // 셰이더가 읽는 모든 location이 같은 format으로 제공되는지 확인
+    pub fn validate_vertex_input(
+        &self,
+        attributes: &[vk::VertexInputAttributeDescription],
+    ) -> Result<(), ShaderReflectionError> {
+        for input in &self.vertex_inputs {
+            let attribute = attributes
+                .iter()
+                .find(|attribute| attribute.location == input.location)
+                .ok_or(ShaderReflectionError::MissingVertexAttribute(
+                    input.location,
+                ))?;
+
+            if attribute.format != input.format {
+                return Err(ShaderReflectionError::VertexAttributeFormatMismatch {
+                    location: input.location,
+                    shader: input.format,
+                    vertex: attribute.format,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    // std140 블록 크기와 멤버별 offset을 Rust 구조체와 비교
+    pub fn validate_uniform_buffer<T: UniformLayout>(
+        &self,
+        set: u32,
+        binding: u32,
+    ) -> Result<(), ShaderReflectionError> {
+        let block = self
+            .bindings
+            .iter()
+            .find(|b| {
+                b.set == set
+                    && b.binding == binding
+                    && b.descriptor_type == vk::DescriptorType::UNIFORM_BUFFER
+            })
+            .and_then(|b| b.block.as_ref())
+            .ok_or(ShaderReflectionError::MissingUniformBuffer { set, binding })?;
+
+        let rust_size = std::mem::size_of::<T>() as u32;
+        if block.size != rust_size {
+            return Err(ShaderReflectionError::UniformBufferSizeMismatch {
+                set,
+                binding,
+                shader: block.size,
+                rust: rust_size,
+            });
+        }
+
+        if block.member_offsets.len() != T::MEMBER_OFFSETS.len() {
+            return Err(ShaderReflectionError::UniformBufferMemberCountMismatch {
+                set,
+                binding,
+                shader: block.member_offsets.len(),
+                rust: T::MEMBER_OFFSETS.len(),
+            });
+        }
+
+        for (member, (&shader, &rust)) in block
+            .member_offsets
+            .iter()
+            .zip(T::MEMBER_OFFSETS)
+            .enumerate()
+        {
+            if shader != rust {
+                return Err(ShaderReflectionError::UniformBufferMemberOffsetMismatch {
+                    set,
+                    binding,
+                    member,
+                    shader,
+                    rust,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_module(spirv: &[u8]) -> Result<Module, ShaderReflectionError> {
+    if spirv.len() < 20 || !spirv.len().is_multiple_of(4) {
+        return Err(ShaderReflectionError::InvalidSpirv("unexpected size"));
+    }
+
+    let words: Vec<u32> = spirv
+        .chunks_exact(4)
+        .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+        .collect();
+
+    if words[0] != SPIRV_MAGIC {
+        return Err(ShaderReflectionError::InvalidSpirv("bad magic number"));
+    }
+
+    let mut module = Module::default();
+    let mut offset = 5;
+
+    while offset < words.len() {
+        let word_count = (words[offset] >> 16) as usize;
+        let opcode = words[offset] & 0xffff;
+
+        if word_count == 0 || offset + word_count > words.len() {
+            return Err(ShaderReflectionError::InvalidSpirv("truncated instruction"));
+        }
+
+        let operands = &words[offset + 1..offset + word_count];
+        offset += word_count;
+
+        let operand = |index: usize| {
+            operands
+                .get(index)
+                .copied()
+                .ok_or(ShaderReflectionError::InvalidSpirv("missing operand"))
+        };
+
+        match opcode {
+            // 첫 번째 entry point만 사용
+            OP_ENTRY_POINT if module.entry_point.is_none() => {
+                let execution_model = operand(0)?;
+                // 이름은 NUL로 끝나는 4바이트 단위 문자열
+                let name_words = operands
+                    .get(2..)
+                    .ok_or(ShaderReflectionError::InvalidSpirv("missing operand"))?
+                    .iter()
+                    .position(|word| word.to_le_bytes().contains(&0))
+                    .ok_or(ShaderReflectionError::InvalidSpirv("unterminated string"))?
+                    + 1;
+                let interface = operands
+                    .get(2 + name_words..)
+                    .ok_or(ShaderReflectionError::InvalidSpirv("missing operand"))?
+                    .to_vec();
+
+                module.entry_point = Some((execution_model, interface));
+            }
+            OP_TYPE_BOOL => {
+                module.types.insert(operand(0)?, SpirvType::Bool);
+            }
+            OP_TYPE_INT => {
+                module.types.insert(
+                    operand(0)?,
+                    SpirvType::Int {
+                        width: operand(1)?,
+                        signed: operand(2)? != 0,
+                    },
+                );
+            }
+            OP_TYPE_FLOAT => {
+                module
+                    .types
+                    .insert(operand(0)?, SpirvType::Float { width: operand(1)? });
+            }
+            OP_TYPE_VECTOR => {
+                module.types.insert(
+                    operand(0)?,
+                    SpirvType::Vector {
+                        component: operand(1)?,
+                        count: operand(2)?,
+                    },
+                );
+            }
+            OP_TYPE_MATRIX => {
+                module.types.insert(
+                    operand(0)?,
+                    SpirvType::Matrix {
+                        column: operand(1)?,
+                        count: operand(2)?,
+                    },
+                );
+            }
+            OP_TYPE_IMAGE => {
+                module.types.insert(
+                    operand(0)?,
+                    SpirvType::Image {
+                        dim: operand(2)?,
+                        sampled: operand(6)?,
+                    },
+                );
+            }
+            OP_TYPE_SAMPLER => {
+                module.types.insert(operand(0)?, SpirvType::Sampler);
+            }
+            OP_TYPE_SAMPLED_IMAGE => {
+                module.types.insert(operand(0)?, SpirvType::SampledImage);
+            }
+            OP_TYPE_ARRAY => {
+                // 길이는 OpConstant id, 상수가 모두 앞에서 정의됨
+                let length_id = operand(2)?;
+                let length = *module
+                    .constants
+                    .get(&length_id)
+                    .ok_or(ShaderReflectionError::InvalidSpirv("unknown array length"))?;
+
+                module.types.insert(
+                    operand(0)?,
+                    SpirvType::Array {
+                        element: operand(1)?,
+                        length,
+                    },
+                );
+            }
+            OP_TYPE_RUNTIME_ARRAY => {
+                module.types.insert(operand(0)?, SpirvType::RuntimeArray);
+            }
+            OP_TYPE_STRUCT => {
+                let members = operands
+                    .get(1..)
+                    .ok_or(ShaderReflectionError::InvalidSpirv("missing operand"))?
+                    .to_vec();
+
+                module
+                    .types
+                    .insert(operand(0)?, SpirvType::Struct { members });
+            }
+            OP_TYPE_POINTER => {
+                module.types.insert(
+                    operand(0)?,
+                    SpirvType::Pointer {
+                        pointee: operand(2)?,
+                    },
+                );
+            }
+            OP_CONSTANT => {
+                // 32비트 이하 정수 상수만 배열 길이로 사용
+                module.constants.insert(operand(1)?, operand(2)?);
+            }
+            OP_VARIABLE => {
+                module
+                    .variables
+                    .insert(operand(1)?, (operand(0)?, operand(2)?));
+            }
+            OP_DECORATE => {
+                let decorations = module.decorations.entry(operand(0)?).or_default();
+
+                match operand(1)? {
+                    DECORATION_BLOCK => decorations.block = true,
+                    DECORATION_BUFFER_BLOCK => decorations.buffer_block = true,
+                    DECORATION_BUILT_IN => decorations.built_in = true,
+                    DECORATION_LOCATION => decorations.location = Some(operand(2)?),
+                    DECORATION_BINDING => decorations.binding = Some(operand(2)?),
+                    DECORATION_DESCRIPTOR_SET => decorations.set = Some(operand(2)?),
+                    DECORATION_ARRAY_STRIDE => decorations.array_stride = Some(operand(2)?),
+                    _ => {}
+                }
+            }
+            OP_MEMBER_DECORATE => {
+                let decorations = module
+                    .member_decorations
+                    .entry((operand(0)?, operand(1)?))
+                    .or_default();
+
+                match operand(2)? {
+                    DECORATION_OFFSET => decorations.offset = Some(operand(3)?),
+                    DECORATION_MATRIX_STRIDE => decorations.matrix_stride = Some(operand(3)?),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(module)
+}
+
+impl Module {
+    // (descriptor type, 배열 크기, 블록 레이아웃)
+    fn descriptor_type(
+        &self,
+        type_id: u32,
+        storage_class: u32,
+    ) -> Option<(vk::DescriptorType, u32, Option<BlockLayout>)> {
+        let (type_id, count) = match self.types.get(&type_id)? {
+            SpirvType::Array { element, length } => (*element, *length),
+            _ => (type_id, 1),
+        };
+
+        let decorations = self.decorations.get(&type_id);
+
+        let descriptor_type = match (self.types.get(&type_id)?, storage_class) {
+            (SpirvType::Sampler, _) => vk::DescriptorType::SAMPLER,
+            (SpirvType::SampledImage, _) => vk::DescriptorType::COMBINED_IMAGE_SAMPLER,
+            (SpirvType::Image { dim, sampled }, _) => match (*dim, *sampled) {
+                (DIM_SUBPASS_DATA, _) => vk::DescriptorType::INPUT_ATTACHMENT,
+                (DIM_BUFFER, 2) => vk::DescriptorType::STORAGE_TEXEL_BUFFER,
+                (DIM_BUFFER, _) => vk::DescriptorType::UNIFORM_TEXEL_BUFFER,
+                (_, 2) => vk::DescriptorType::STORAGE_IMAGE,
+                _ => vk::DescriptorType::SAMPLED_IMAGE,
+            },
+            (SpirvType::Struct { .. }, STORAGE_CLASS_STORAGE_BUFFER) => {
+                vk::DescriptorType::STORAGE_BUFFER
+            }
+            (SpirvType::Struct { .. }, STORAGE_CLASS_UNIFORM)
+                if decorations.is_some_and(|d| d.buffer_block) =>
+            {
+                vk::DescriptorType::STORAGE_BUFFER
+            }
+            (SpirvType::Struct { .. }, STORAGE_CLASS_UNIFORM)
+                if decorations.is_some_and(|d| d.block) =>
+            {
+                vk::DescriptorType::UNIFORM_BUFFER
+            }
+            _ => return None,
+        };
+
+        let block = matches!(
+            descriptor_type,
+            vk::DescriptorType::UNIFORM_BUFFER | vk::DescriptorType::STORAGE_BUFFER
+        )
+        .then(|| BlockLayout {
+            size: self.size_of(type_id),
+            member_offsets: self.member_offsets(type_id),
+        });
+
+        Some((descriptor_type, count, block))
+    }
+
+    // 블록 시작 기준의 멤버 offset, 중첩 구조체는 펼쳐서 말단 멤버 순서대로 반환
+    // naga는 uniform 구조체를 멤버 하나짜리 Block 구조체로 감싸므로 펼쳐야 Rust 필드와 대응됨
+    fn member_offsets(&self, type_id: u32) -> Vec<u32> {
+        let mut offsets = Vec::new();
+        self.collect_member_offsets(type_id, 0, &mut offsets);
+
+        offsets
+    }
+
+    fn collect_member_offsets(&self, type_id: u32, base: u32, offsets: &mut Vec<u32>) {
+        let Some(SpirvType::Struct { members }) = self.types.get(&type_id) else {
+            return;
+        };
+
+        for (index, &member) in members.iter().enumerate() {
+            let offset = base
+                + self
+                    .member_decorations
+                    .get(&(type_id, index as u32))
+                    .and_then(|d| d.offset)
+                    .unwrap_or(0);
+
+            if matches!(self.types.get(&member), Some(SpirvType::Struct { .. })) {
+                self.collect_member_offsets(member, offset, offsets);
+            } else {
+                offsets.push(offset);
+            }
+        }
+    }
+
+    // offset decoration을 반영한 바이트 크기, runtime array는 0으로 계산
+    fn size_of(&self, type_id: u32) -> u32 {
+        match self.types.get(&type_id) {
+            Some(SpirvType::Bool) => 4,
+            Some(SpirvType::Int { width, .. }) | Some(SpirvType::Float { width }) => width / 8,
+            Some(SpirvType::Vector { component, count }) => self.size_of(*component) * count,
+            Some(SpirvType::Matrix { column, count }) => self.size_of(*column) * count,
+            Some(SpirvType::Array { element, length }) => {
+                let stride = self
+                    .decorations
+                    .get(&type_id)
+                    .and_then(|d| d.array_stride)
+                    .unwrap_or_else(|| self.size_of(*element));
+                stride * length
+            }
+            Some(SpirvType::Struct { members }) => members
+                .iter()
+                .enumerate()
+                .map(|(index, &member)| {
+                    let decorations = self.member_decorations.get(&(type_id, index as u32));
+                    let offset = decorations.and_then(|d| d.offset).unwrap_or(0);
+
+                    let size = match (self.types.get(&member), decorations) {
+                        (
+                            Some(SpirvType::Matrix { count, .. }),
+                            Some(MemberDecorations {
+                                matrix_stride: Some(stride),
+                                ..
+                            }),
+                        ) => stride * count,
+                        _ => self.size_of(member),
+                    };
+
+                    offset + size
+                })
+                .max()
+                .unwrap_or(0),
+            _ => 0,
+        }
+    }
+
+    fn vertex_format(&self, type_id: u32) -> Option<vk::Format> {
+        let (component, count) = match self.types.get(&type_id)? {
+            SpirvType::Vector { component, count } => (*component, *count),
+            _ => (type_id, 1),
+        };
+
+        let format = match (self.types.get(&component)?, count) {
+            (SpirvType::Float { width: 32 }, 1) => vk::Format::R32_SFLOAT,
+            (SpirvType::Float { width: 32 }, 2) => vk::Format::R32G32_SFLOAT,
+            (SpirvType::Float { width: 32 }, 3) => vk::Format::R32G32B32_SFLOAT,
+            (SpirvType::Float { width: 32 }, 4) => vk::Format::R32G32B32A32_SFLOAT,
+            (SpirvType::Int { width: 32, signed }, count) => match (signed, count) {
+                (false, 1) => vk::Format::R32_UINT,
+                (false, 2) => vk::Format::R32G32_UINT,
+                (false, 3) => vk::Format::R32G32B32_UINT,
+                (false, 4) => vk::Format::R32G32B32A32_UINT,
+                (true, 1) => vk::Format::R32_SINT,
+                (true, 2) => vk::Format::R32G32_SINT,
+                (true, 3) => vk::Format::R32G32B32_SINT,
+                (true, 4) => vk::Format::R32G32B32A32_SINT,
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        Some(format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eren_vertex::VkVertexLayout;
+
+    use super::*;
+    use crate::test_vertex_input::{ubo::UniformBufferObject, vertex::Vertex};
+
+    const VERT_SHADER: &[u8] =
+        include_bytes!("../android/app/src/main/assets/shaders/shader.vert.spv");
+    const FRAG_SHADER: &[u8] =
+        include_bytes!("../android/app/src/main/assets/shaders/shader.frag.spv");
+    const POST_PROCESS_FRAG_SHADER: &[u8] =
+        include_bytes!("../android/app/src/main/assets/shaders/post_process.frag.spv");
+
+    fn scene_reflection() -> PipelineReflection {
+        PipelineReflection::new(&[VERT_SHADER, FRAG_SHADER]).unwrap()
+    }
+
+    fn attribute(location: u32, format: vk::Format) -> vk::VertexInputAttributeDescription {
+        vk::VertexInputAttributeDescription {
+            location,
+            binding: 0,
+            format,
+            offset: 0,
+        }
+    }
+
+    #[test]
+    fn scene_shader_matches_vertex_and_uniform_layout() {
+        let reflection = scene_reflection();
+
+        reflection
+            .validate_vertex_input(Vertex::ATTRIBUTE_DESCRIPTIONS)
+            .unwrap();
+        reflection
+            .validate_uniform_buffer::<UniformBufferObject>(0, 0)
+            .unwrap();
+    }
+
+    #[test]
+    fn missing_vertex_attribute() {
+        let attributes = [
+            attribute(0, vk::Format::R32G32B32_SFLOAT),
+            attribute(1, vk::Format::R32G32B32_SFLOAT),
+        ];
+
+        assert!(matches!(
+            scene_reflection().validate_vertex_input(&attributes),
+            Err(ShaderReflectionError::MissingVertexAttribute(2))
+        ));
+    }
+
+    #[test]
+    fn vertex_attribute_format_mismatch() {
+        // tex_coord를 vec3로 잘못 선언한 경우
+        let attributes = [
+            attribute(0, vk::Format::R32G32B32_SFLOAT),
+            attribute(1, vk::Format::R32G32B32_SFLOAT),
+            attribute(2, vk::Format::R32G32B32_SFLOAT),
+        ];
+
+        assert!(matches!(
+            scene_reflection().validate_vertex_input(&attributes),
+            Err(ShaderReflectionError::VertexAttributeFormatMismatch {
+                location: 2,
+                shader: vk::Format::R32G32_SFLOAT,
+                vertex: vk::Format::R32G32B32_SFLOAT,
+            })
+        ));
+    }
+
+    #[test]
+    fn uniform_buffer_size_mismatch() {
+        // proj 행렬이 빠진 구조체
+        #[repr(C)]
+        struct ModelView {
+            model: [f32; 16],
+            view: [f32; 16],
+        }
+
+        impl UniformLayout for ModelView {
+            const MEMBER_OFFSETS: &'static [u32] = &[
+                std::mem::offset_of!(ModelView, model) as u32,
+                std::mem::offset_of!(ModelView, view) as u32,
+            ];
+        }
+
+        assert!(matches!(
+            scene_reflection().validate_uniform_buffer::<ModelView>(0, 0),
+            Err(ShaderReflectionError::UniformBufferSizeMismatch {
+                set: 0,
+                binding: 0,
+                shader: 192,
+                rust: 128,
+            })
+        ));
+    }
+
+    #[test]
+    fn uniform_buffer_member_offset_mismatch() {
+        // 크기(192바이트)와 필드 수는 같지만 view와 proj의 자리가 바뀌어
+        // proj 앞의 작은 필드 때문에 세 번째 멤버의 offset이 달라진 구조체
+        #[repr(C)]
+        struct Swapped {
+            model: [f32; 16],
+            proj: [f32; 4],
+            view: [f32; 28],
+        }
+
+        impl UniformLayout for Swapped {
+            const MEMBER_OFFSETS: &'static [u32] = &[
+                std::mem::offset_of!(Swapped, model) as u32,
+                std::mem::offset_of!(Swapped, proj) as u32,
+                std::mem::offset_of!(Swapped, view) as u32,
+            ];
+        }
+
+        assert_eq!(
+            std::mem::size_of::<Swapped>(),
+            std::mem::size_of::<UniformBufferObject>()
+        );
+        assert!(matches!(
+            scene_reflection().validate_uniform_buffer::<Swapped>(0, 0),
+            Err(ShaderReflectionError::UniformBufferMemberOffsetMismatch {
+                set: 0,
+                binding: 0,
+                member: 2,
+                shader: 128,
+                rust: 80,
+            })
+        ));
+    }
+
+    #[test]
+    fn missing_uniform_buffer() {
+        // binding 1은 텍스처
+        assert!(matches!(
+            scene_reflection().validate_uniform_buffer::<UniformBufferObject>(0, 1),
+            Err(ShaderReflectionError::MissingUniformBuffer { set: 0, binding: 1 })
+        ));
+    }
+
+    #[test]
+    fn descriptor_type_conflict() {
+        // 장면 셰이더의 binding 0은 uniform buffer, 후처리 셰이더의 binding 0은 input attachment
+        assert!(matches!(
+            PipelineReflection::new(&[VERT_SHADER, POST_PROCESS_FRAG_SHADER]),
+            Err(ShaderReflectionError::DescriptorTypeConflict {
+                set: 0,
+                binding: 0,
+                first: vk::DescriptorType::UNIFORM_BUFFER,
+                second: vk::DescriptorType::INPUT_ATTACHMENT,
+            })
+        ));
+    }
+
+    #[test]
+    fn truncated_spirv() {
+        // 헤더(5 word) 뒤 첫 명령(OpCapability, 2 word)의 중간에서 자름
+        assert!(matches!(
+            ShaderReflection::new(&VERT_SHADER[..24]),
+            Err(ShaderReflectionError::InvalidSpirv("truncated instruction"))
+        ));
+        assert!(matches!(
+            ShaderReflection::new(&VERT_SHADER[..VERT_SHADER.len() - 1]),
+            Err(ShaderReflectionError::InvalidSpirv("unexpected size"))
+        ));
+    }
+
+    // 헤더 뒤에 명령 하나만 있는 모듈
+    fn single_instruction_module(instruction: &[u32]) -> Vec<u8> {
+        [SPIRV_MAGIC, 0x0001_0000, 0, 1, 0]
+            .iter()
+            .chain(instruction)
+            .flat_map(|word| word.to_le_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn truncated_entry_point() {
+        // execution model만 있고 entry point id와 이름이 없음
+        let spirv = single_instruction_module(&[(2 << 16) | OP_ENTRY_POINT, 0]);
+        assert!(matches!(
+            ShaderReflection::new(&spirv),
+            Err(ShaderReflectionError::InvalidSpirv("missing operand"))
+        ));
+
+        // 이름이 NUL 없이 명령 끝에 닿음
+        let spirv = single_instruction_module(&[(4 << 16) | OP_ENTRY_POINT, 0, 1, 0x4141_4141]);
+        assert!(matches!(
+            ShaderReflection::new(&spirv),
+            Err(ShaderReflectionError::InvalidSpirv("unterminated string"))
+        ));
+    }
+
+    #[test]
+    fn truncated_struct() {
+        // result id가 없는 OpTypeStruct
+        let spirv = single_instruction_module(&[(1 << 16) | OP_TYPE_STRUCT]);
+        assert!(matches!(
+            ShaderReflection::new(&spirv),
+            Err(ShaderReflectionError::InvalidSpirv("missing operand"))
+        ));
+    }
+}
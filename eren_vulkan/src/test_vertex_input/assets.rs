@@ -0,0 +1,63 @@
+use eren_common::gltf_loader::{self, GltfLoadError};
+use thiserror::Error;
+
+use crate::{
+    asset::{AssetLoadError, AssetSource},
+    test_vertex_input::mesh::{Mesh, MeshSource, TestScene},
+};
+
+const VERT_SHADER_PATH: &str = "shaders/shader.vert.spv";
+const FRAG_SHADER_PATH: &str = "shaders/shader.frag.spv";
+const POST_PROCESS_VERT_SHADER_PATH: &str = "shaders/post_process.vert.spv";
+const POST_PROCESS_FRAG_SHADER_PATH: &str = "shaders/post_process.frag.spv";
+const POST_PROCESS_MS_FRAG_SHADER_PATH: &str = "shaders/post_process_ms.frag.spv";
+
+#[derive(Debug, Error)]
+pub enum TestAssetsLoadError {
+    #[error("Failed to load asset: {0}")]
+    LoadAsset(#[from] AssetLoadError),
+
+    #[error("Failed to load glTF mesh: {0}")]
+    LoadGltf(#[from] GltfLoadError<AssetLoadError>),
+}
+
+// 렌더러를 만들기 전에 장면에 필요한 asset을 비동기로 미리 읽어 둠
+// 디바이스 손실 후 render context를 다시 만들 때도 그대로 재사용
+pub struct TestAssets {
+    pub mesh: Mesh,
+    pub vert_shader: Vec<u8>,
+    pub frag_shader: Vec<u8>,
+    pub post_process_vert_shader: Vec<u8>,
+    pub post_process_frag_shader: Vec<u8>,
+    // MSAA 사용 시 subpassInputMS로 depth를 읽는 후처리 셰이더
+    pub post_process_ms_frag_shader: Vec<u8>,
+    // 텍스처가 없는 장면은 None
+    pub texture: Option<Vec<u8>>,
+}
+
+impl TestAssets {
+    pub async fn load(
+        source: &dyn AssetSource,
+        scene: TestScene,
+    ) -> Result<Self, TestAssetsLoadError> {
+        let mesh = match scene.mesh_source() {
+            MeshSource::Builtin { vertices, indices } => Mesh::from_builtin(vertices, indices),
+            MeshSource::Gltf(path) => Mesh::from_gltf(gltf_loader::load_gltf(source, path).await?),
+        };
+
+        let texture = match scene.texture_asset() {
+            Some(path) => Some(source.load(path).await?),
+            None => None,
+        };
+
+        Ok(Self {
+            mesh,
+            vert_shader: source.load(VERT_SHADER_PATH).await?,
+            frag_shader: source.load(FRAG_SHADER_PATH).await?,
+            post_process_vert_shader: source.load(POST_PROCESS_VERT_SHADER_PATH).await?,
+            post_process_frag_shader: source.load(POST_PROCESS_FRAG_SHADER_PATH).await?,
+            post_process_ms_frag_shader: source.load(POST_PROCESS_MS_FRAG_SHADER_PATH).await?,
+            texture,
+        })
+    }
+}
@@ -0,0 +1,326 @@
+use ash::vk;
+use glam::{Vec2, Vec3};
+
+use eren_common::gltf_loader::MeshData;
+
+use crate::test_vertex_input::vertex::Vertex;
+
+// TestSubpass가 그릴 장면
+#[derive(Clone, Copy, Debug)]
+pub enum TestScene {
+    Quad,
+    Cube,
+    TexturedQuad,
+    GltfSphere,
+}
+
+// 코드에 정의된 정점/인덱스 또는 asset 디렉터리의 glTF 파일
+pub enum MeshSource {
+    Builtin {
+        vertices: &'static [Vertex],
+        indices: &'static [u16],
+    },
+    Gltf(&'static str),
+}
+
+// glTF 모델의 가장 긴 변을 이 크기로 맞춰 카메라 안에 들어오게 함
+const GLTF_FIT_SIZE: f32 = 1.2;
+
+// glTF 모델의 정점 색에 미리 계산해 넣는 조명 (모델 공간 기준)
+const GLTF_LIGHT_DIR: Vec3 = Vec3::new(0.5, 0.3, 0.8);
+const GLTF_AMBIENT: f32 = 0.3;
+
+// 사각형 모서리의 텍스처 좌표
+// 카메라가 +X+Y 쪽 위에서 내려다보므로 이미지의 위쪽을 -Y에 두어야 바로 보임
+const QUAD_TEX_COORDS: [Vec2; 4] = [
+    Vec2::new(0.0, 0.0),
+    Vec2::new(1.0, 0.0),
+    Vec2::new(1.0, 1.0),
+    Vec2::new(0.0, 1.0),
+];
+
+const QUAD_VERTICES: [Vertex; 4] = [
+    Vertex {
+        pos: Vec3::new(-0.5, -0.5, 0.0),
+        color: Vec3::new(1.0, 0.0, 0.0),
+        tex_coord: QUAD_TEX_COORDS[0],
+    },
+    Vertex {
+        pos: Vec3::new(0.5, -0.5, 0.0),
+        color: Vec3::new(0.0, 1.0, 0.0),
+        tex_coord: QUAD_TEX_COORDS[1],
+    },
+    Vertex {
+        pos: Vec3::new(0.5, 0.5, 0.0),
+        color: Vec3::new(0.0, 0.0, 1.0),
+        tex_coord: QUAD_TEX_COORDS[2],
+    },
+    Vertex {
+        pos: Vec3::new(-0.5, 0.5, 0.0),
+        color: Vec3::new(1.0, 1.0, 1.0),
+        tex_coord: QUAD_TEX_COORDS[3],
+    },
+];
+
+// 텍스처 색이 그대로 보이도록 정점 색은 흰색
+const TEXTURED_QUAD_VERTICES: [Vertex; 4] = [
+    Vertex {
+        pos: Vec3::new(-0.5, -0.5, 0.0),
+        color: Vec3::ONE,
+        tex_coord: QUAD_TEX_COORDS[0],
+    },
+    Vertex {
+        pos: Vec3::new(0.5, -0.5, 0.0),
+        color: Vec3::ONE,
+        tex_coord: QUAD_TEX_COORDS[1],
+    },
+    Vertex {
+        pos: Vec3::new(0.5, 0.5, 0.0),
+        color: Vec3::ONE,
+        tex_coord: QUAD_TEX_COORDS[2],
+    },
+    Vertex {
+        pos: Vec3::new(-0.5, 0.5, 0.0),
+        color: Vec3::ONE,
+        tex_coord: QUAD_TEX_COORDS[3],
+    },
+];
+
+const QUAD_INDICES: [u16; 6] = [0, 1, 2, 2, 3, 0];
+
+const fn cube_face(corners: [[f32; 3]; 4], color: Vec3) -> [Vertex; 4] {
+    [
+        Vertex {
+            pos: Vec3::new(corners[0][0], corners[0][1], corners[0][2]),
+            color,
+            tex_coord: QUAD_TEX_COORDS[0],
+        },
+        Vertex {
+            pos: Vec3::new(corners[1][0], corners[1][1], corners[1][2]),
+            color,
+            tex_coord: QUAD_TEX_COORDS[1],
+        },
+        Vertex {
+            pos: Vec3::new(corners[2][0], corners[2][1], corners[2][2]),
+            color,
+            tex_coord: QUAD_TEX_COORDS[2],
+        },
+        Vertex {
+            pos: Vec3::new(corners[3][0], corners[3][1], corners[3][2]),
+            color,
+            tex_coord: QUAD_TEX_COORDS[3],
+        },
+    ]
+}
+
+// 면마다 색이 다르도록 면별로 정점 4개씩 사용
+static CUBE_FACES: [[Vertex; 4]; 6] = [
+    // +X
+    cube_face(
+        [
+            [0.5, -0.5, -0.5],
+            [0.5, 0.5, -0.5],
+            [0.5, 0.5, 0.5],
+            [0.5, -0.5, 0.5],
+        ],
+        Vec3::new(1.0, 0.0, 0.0),
+    ),
+    // -X
+    cube_face(
+        [
+            [-0.5, 0.5, -0.5],
+            [-0.5, -0.5, -0.5],
+            [-0.5, -0.5, 0.5],
+            [-0.5, 0.5, 0.5],
+        ],
+        Vec3::new(0.0, 1.0, 1.0),
+    ),
+    // +Y
+    cube_face(
+        [
+            [0.5, 0.5, -0.5],
+            [-0.5, 0.5, -0.5],
+            [-0.5, 0.5, 0.5],
+            [0.5, 0.5, 0.5],
+        ],
+        Vec3::new(0.0, 1.0, 0.0),
+    ),
+    // -Y
+    cube_face(
+        [
+            [-0.5, -0.5, -0.5],
+            [0.5, -0.5, -0.5],
+            [0.5, -0.5, 0.5],
+            [-0.5, -0.5, 0.5],
+        ],
+        Vec3::new(1.0, 0.0, 1.0),
+    ),
+    // +Z
+    cube_face(
+        [
+            [-0.5, -0.5, 0.5],
+            [0.5, -0.5, 0.5],
+            [0.5, 0.5, 0.5],
+            [-0.5, 0.5, 0.5],
+        ],
+        Vec3::new(0.0, 0.0, 1.0),
+    ),
+    // -Z
+    cube_face(
+        [
+            [-0.5, 0.5, -0.5],
+            [0.5, 0.5, -0.5],
+            [0.5, -0.5, -0.5],
+            [-0.5, -0.5, -0.5],
+        ],
+        Vec3::new(1.0, 1.0, 0.0),
+    ),
+];
+
+const CUBE_INDICES: [u16; 36] = [
+    0, 1, 2, 2, 3, 0, // +X
+    4, 5, 6, 6, 7, 4, // -X
+    8, 9, 10, 10, 11, 8, // +Y
+    12, 13, 14, 14, 15, 12, // -Y
+    16, 17, 18, 18, 19, 16, // +Z
+    20, 21, 22, 22, 23, 20, // -Z
+];
+
+impl TestScene {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TestScene::Quad => "quad",
+            TestScene::Cube => "cube",
+            TestScene::TexturedQuad => "textured_quad",
+            TestScene::GltfSphere => "gltf_sphere",
+        }
+    }
+
+    pub fn mesh_source(&self) -> MeshSource {
+        match self {
+            TestScene::Quad => MeshSource::Builtin {
+                vertices: &QUAD_VERTICES,
+                indices: &QUAD_INDICES,
+            },
+            TestScene::Cube => MeshSource::Builtin {
+                vertices: CUBE_FACES.as_flattened(),
+                indices: &CUBE_INDICES,
+            },
+            TestScene::TexturedQuad => MeshSource::Builtin {
+                vertices: &TEXTURED_QUAD_VERTICES,
+                indices: &QUAD_INDICES,
+            },
+            TestScene::GltfSphere => MeshSource::Gltf("models/sphere.gltf"),
+        }
+    }
+
+    // 장면에서 샘플링할 텍스처의 asset 경로, 없으면 흰색 텍스처 사용
+    pub fn texture_asset(&self) -> Option<&'static str> {
+        match self {
+            TestScene::Quad | TestScene::Cube | TestScene::GltfSphere => None,
+            TestScene::TexturedQuad => Some("android_robot.png"),
+        }
+    }
+}
+
+// 정점이 65536개를 넘는 메시는 32비트 인덱스를 사용
+pub enum MeshIndices {
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+}
+
+impl MeshIndices {
+    // 모든 인덱스가 16비트에 들어가면 인덱스 버퍼 크기를 줄이기 위해 u16으로 변환
+    pub fn from_u32(indices: Vec<u32>) -> Self {
+        if indices.iter().all(|&index| index <= u16::MAX as u32) {
+            MeshIndices::U16(indices.into_iter().map(|index| index as u16).collect())
+        } else {
+            MeshIndices::U32(indices)
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            MeshIndices::U16(indices) => indices.len(),
+            MeshIndices::U32(indices) => indices.len(),
+        }
+    }
+
+    pub fn index_type(&self) -> vk::IndexType {
+        match self {
+            MeshIndices::U16(_) => vk::IndexType::UINT16,
+            MeshIndices::U32(_) => vk::IndexType::UINT32,
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            MeshIndices::U16(indices) => bytemuck::cast_slice(indices),
+            MeshIndices::U32(indices) => bytemuck::cast_slice(indices),
+        }
+    }
+}
+
+pub struct Mesh {
+    pub vertices: Vec<Vertex>,
+    pub indices: MeshIndices,
+}
+
+impl Mesh {
+    pub fn from_builtin(vertices: &[Vertex], indices: &[u16]) -> Self {
+        Self {
+            vertices: vertices.to_vec(),
+            indices: MeshIndices::U16(indices.to_vec()),
+        }
+    }
+
+    // glTF는 Y-up이므로 X축으로 90도 회전하여 Z-up인 테스트 장면에 맞추고,
+    // 중심을 원점으로 옮겨 GLTF_FIT_SIZE 크기로 맞춤
+    pub fn from_gltf(data: MeshData) -> Self {
+        let to_scene = |v: Vec3| Vec3::new(v.x, -v.z, v.y);
+
+        let (min, max) = data.positions.iter().fold(
+            (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+            |(min, max), &position| {
+                let position = to_scene(position);
+                (min.min(position), max.max(position))
+            },
+        );
+        let center = (min + max) * 0.5;
+        let extent = (max - min).max_element();
+        let scale = if extent > 0.0 {
+            GLTF_FIT_SIZE / extent
+        } else {
+            1.0
+        };
+
+        let light_dir = GLTF_LIGHT_DIR.normalize();
+
+        let vertices = data
+            .positions
+            .into_iter()
+            .zip(data.normals)
+            .zip(data.tex_coords)
+            .map(|((position, normal), tex_coord)| {
+                // normal이 없는 정점은 조명 없이 흰색
+                let color = if normal == Vec3::ZERO {
+                    Vec3::ONE
+                } else {
+                    let diffuse = to_scene(normal).dot(light_dir).max(0.0);
+                    Vec3::splat(GLTF_AMBIENT + (1.0 - GLTF_AMBIENT) * diffuse)
+                };
+
+                Vertex {
+                    pos: (to_scene(position) - center) * scale,
+                    color,
+                    tex_coord,
+                }
+            })
+            .collect();
+
+        Self {
+            vertices,
+            indices: MeshIndices::from_u32(data.indices),
+        }
+    }
+}
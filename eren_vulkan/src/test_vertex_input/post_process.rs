@@ -0,0 +1,296 @@
+use std::sync::Arc;
+
+use ash::vk;
+use eren_vulkan_render_shared::{
+    device::{
+        DescriptorPoolCreationError, DescriptorSetAllocationError,
+        DescriptorSetLayoutCreationError, Device, GraphicsPipelineCreationError,
+        PipelineLayoutCreationError,
+    },
+    pipeline::graphics::GraphicsPipeline,
+};
+use thiserror::Error;
+
+use crate::{
+    capabilities::VulkanCapabilities,
+    debug_utils::{DebugUtils, POST_PROCESS_LABEL_COLOR},
+    shader_reflection::{PipelineReflection, ShaderReflectionError},
+    test_vertex_input::{assets::TestAssets, render_pass::FrameAttachments, subpass::SubpassInfo},
+};
+
+#[derive(Debug, Error)]
+pub enum PostProcessSubpassInitializationError {
+    #[error("Failed to reflect shaders: {0}")]
+    ReflectShaders(#[from] ShaderReflectionError),
+
+    #[error("Failed to create descriptor set layout: {0}")]
+    CreateDescriptorSetLayout(#[from] DescriptorSetLayoutCreationError),
+
+    #[error("Failed to create pipeline layout: {0}")]
+    CreatePipelineLayout(#[from] PipelineLayoutCreationError),
+
+    #[error("Failed to create graphics pipeline: {0}")]
+    CreateGraphicsPipeline(#[from] GraphicsPipelineCreationError),
+
+    #[error("Failed to create descriptor pool: {0}")]
+    CreateDescriptorPool(#[from] DescriptorPoolCreationError),
+
+    #[error("Failed to allocate descriptor sets: {0}")]
+    AllocateDescriptorSets(#[from] DescriptorSetAllocationError),
+}
+
+// 이전 subpass의 color/depth를 input attachment로 읽어 화면 전체에 후처리
+pub struct PostProcessSubpass {
+    device: Arc<Device>,
+    debug_utils: DebugUtils,
+    render_area: vk::Rect2D,
+
+    // swapchain 재생성 시 descriptor pool 크기 계산에 사용
+    reflection: PipelineReflection,
+    descriptor_set_layout: vk::DescriptorSetLayout,
+    pipeline_layout: vk::PipelineLayout,
+    pipeline: GraphicsPipeline,
+
+    // input attachment는 framebuffer마다 다르므로 framebuffer 수만큼 descriptor set 사용
+    descriptor_pool: vk::DescriptorPool,
+    descriptor_sets: Vec<vk::DescriptorSet>,
+}
+
+impl PostProcessSubpass {
+    pub fn new(
+        device: Arc<Device>,
+        render_area: vk::Rect2D,
+        subpass: SubpassInfo,
+        capabilities: &VulkanCapabilities,
+        attachments: &[FrameAttachments],
+        assets: &TestAssets,
+    ) -> Result<Self, PostProcessSubpassInitializationError> {
+        let debug_utils = capabilities.debug_utils.clone();
+
+        // MSAA 사용 시 depth는 resolve되지 않으므로 subpassInputMS로 첫 번째 샘플을 읽음
+        let frag_shader_bytes = if capabilities.sample_count == vk::SampleCountFlags::TYPE_1 {
+            &assets.post_process_frag_shader
+        } else {
+            &assets.post_process_ms_frag_shader
+        };
+
+        // binding 0: 장면 color, binding 1: 장면 depth (input attachment)
+        let reflection = PipelineReflection::new(&[
+            assets.post_process_vert_shader.as_slice(),
+            frag_shader_bytes,
+        ])?;
+
+        let input_bindings = reflection.descriptor_set_layout_bindings(0);
+
+        let descriptor_set_layout = device.create_descriptor_set_layout(&input_bindings)?;
+
+        let pipeline_layout = device
+            .create_pipeline_layout(&[descriptor_set_layout], &reflection.push_constant_ranges())?;
+
+        // 정점 버퍼 없이 gl_VertexIndex로 화면을 덮는 삼각형 생성
+        let vertex_input_info = vk::PipelineVertexInputStateCreateInfo::default();
+
+        let input_assembly = vk::PipelineInputAssemblyStateCreateInfo::default()
+            .topology(vk::PrimitiveTopology::TRIANGLE_LIST)
+            .primitive_restart_enable(false);
+
+        let viewport_state = vk::PipelineViewportStateCreateInfo::default()
+            .viewport_count(1)
+            .scissor_count(1);
+
+        let dynamic_states = [vk::DynamicState::VIEWPORT, vk::DynamicState::SCISSOR];
+        let dynamic_state =
+            vk::PipelineDynamicStateCreateInfo::default().dynamic_states(&dynamic_states);
+
+        let rasterizer = vk::PipelineRasterizationStateCreateInfo::default()
+            .depth_clamp_enable(false)
+            .rasterizer_discard_enable(false)
+            .polygon_mode(vk::PolygonMode::FILL)
+            .line_width(1.0)
+            .cull_mode(vk::CullModeFlags::NONE)
+            .front_face(vk::FrontFace::COUNTER_CLOCKWISE)
+            .depth_bias_enable(false);
+
+        let multisampling = vk::PipelineMultisampleStateCreateInfo::default()
+            .sample_shading_enable(false)
+            .rasterization_samples(vk::SampleCountFlags::TYPE_1);
+
+        let color_blend_attachment = vk::PipelineColorBlendAttachmentState::default()
+            .color_write_mask(vk::ColorComponentFlags::RGBA)
+            .blend_enable(false);
+
+        let color_blend_attachment_states = [color_blend_attachment];
+        let color_blending = vk::PipelineColorBlendStateCreateInfo::default()
+            .logic_op_enable(false)
+            .attachments(&color_blend_attachment_states);
+
+        let pipeline_info = vk::GraphicsPipelineCreateInfo::default()
+            .vertex_input_state(&vertex_input_info)
+            .input_assembly_state(&input_assembly)
+            .viewport_state(&viewport_state)
+            .dynamic_state(&dynamic_state)
+            .rasterization_state(&rasterizer)
+            .multisample_state(&multisampling)
+            .color_blend_state(&color_blending)
+            .layout(pipeline_layout)
+            .render_pass(subpass.render_pass)
+            .subpass(subpass.index);
+
+        let pipeline = GraphicsPipeline::with_cache(
+            device.clone(),
+            pipeline_info,
+            subpass.pipeline_cache,
+            Some(&assets.post_process_vert_shader),
+            Some(frag_shader_bytes),
+        )?;
+        debug_utils.set_name(pipeline.handle(), "PostProcessSubpass pipeline");
+
+        let (descriptor_pool, descriptor_sets) = create_input_descriptor_sets(
+            &device,
+            &debug_utils,
+            &reflection,
+            descriptor_set_layout,
+            attachments,
+        )?;
+
+        Ok(Self {
+            device,
+            debug_utils,
+            render_area,
+
+            reflection,
+            descriptor_set_layout,
+            pipeline_layout,
+            pipeline,
+
+            descriptor_pool,
+            descriptor_sets,
+        })
+    }
+
+    // 새 framebuffer의 attachment를 가리키도록 descriptor set만 다시 만듦
+    pub fn on_swapchain_recreated(
+        &mut self,
+        render_area: vk::Rect2D,
+        attachments: &[FrameAttachments],
+    ) -> Result<(), PostProcessSubpassInitializationError> {
+        let (descriptor_pool, descriptor_sets) = create_input_descriptor_sets(
+            &self.device,
+            &self.debug_utils,
+            &self.reflection,
+            self.descriptor_set_layout,
+            attachments,
+        )?;
+
+        self.device.destroy_descriptor_pool(self.descriptor_pool);
+
+        self.render_area = render_area;
+        self.descriptor_pool = descriptor_pool;
+        self.descriptor_sets = descriptor_sets;
+
+        Ok(())
+    }
+
+    pub fn record_commands(&mut self, command_buffer: vk::CommandBuffer, framebuffer_idx: usize) {
+        self.debug_utils.begin_label(
+            command_buffer,
+            "PostProcessSubpass",
+            POST_PROCESS_LABEL_COLOR,
+        );
+
+        self.pipeline.bind_pipeline(command_buffer);
+
+        let viewport = vk::Viewport {
+            x: self.render_area.offset.x as f32,
+            y: self.render_area.offset.y as f32,
+            width: self.render_area.extent.width as f32,
+            height: self.render_area.extent.height as f32,
+            min_depth: 0.0,
+            max_depth: 1.0,
+        };
+
+        self.device.set_viewports(command_buffer, &[viewport]);
+        self.device
+            .set_scissors(command_buffer, &[self.render_area]);
+
+        self.device.bind_graphics_descriptor_sets(
+            command_buffer,
+            self.pipeline_layout,
+            &[self.descriptor_sets[framebuffer_idx]],
+        );
+
+        self.device.draw(command_buffer, 3, 1, 0, 0);
+
+        self.debug_utils.end_label(command_buffer);
+    }
+}
+
+fn create_input_descriptor_sets(
+    device: &Device,
+    debug_utils: &DebugUtils,
+    reflection: &PipelineReflection,
+    descriptor_set_layout: vk::DescriptorSetLayout,
+    attachments: &[FrameAttachments],
+) -> Result<(vk::DescriptorPool, Vec<vk::DescriptorSet>), PostProcessSubpassInitializationError> {
+    let set_count = attachments.len() as u32;
+
+    let descriptor_pool = device
+        .create_descriptor_pool(set_count, &reflection.descriptor_pool_sizes(0, set_count))?;
+
+    let descriptor_set_layouts = vec![descriptor_set_layout; attachments.len()];
+    let descriptor_sets =
+        match device.allocate_descriptor_sets(descriptor_pool, &descriptor_set_layouts) {
+            Ok(descriptor_sets) => descriptor_sets,
+            Err(e) => {
+                device.destroy_descriptor_pool(descriptor_pool);
+                return Err(e.into());
+            }
+        };
+
+    for (idx, (&descriptor_set, frame_attachments)) in
+        descriptor_sets.iter().zip(attachments).enumerate()
+    {
+        debug_utils.set_name(
+            descriptor_set,
+            &format!("PostProcessSubpass descriptor set {}", idx),
+        );
+
+        let color_infos = [vk::DescriptorImageInfo {
+            sampler: vk::Sampler::null(),
+            image_view: frame_attachments.scene_color.view(),
+            image_layout: vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+        }];
+
+        let depth_infos = [vk::DescriptorImageInfo {
+            sampler: vk::Sampler::null(),
+            image_view: frame_attachments.depth.view(),
+            image_layout: vk::ImageLayout::DEPTH_STENCIL_READ_ONLY_OPTIMAL,
+        }];
+
+        device.write_descriptor_sets(&[
+            vk::WriteDescriptorSet::default()
+                .dst_set(descriptor_set)
+                .dst_binding(0)
+                .descriptor_type(vk::DescriptorType::INPUT_ATTACHMENT)
+                .image_info(&color_infos),
+            vk::WriteDescriptorSet::default()
+                .dst_set(descriptor_set)
+                .dst_binding(1)
+                .descriptor_type(vk::DescriptorType::INPUT_ATTACHMENT)
+                .image_info(&depth_infos),
+        ]);
+    }
+
+    Ok((descriptor_pool, descriptor_sets))
+}
+
+impl Drop for PostProcessSubpass {
+    fn drop(&mut self) {
+        self.device.wait_idle();
+
+        self.device.destroy_descriptor_pool(self.descriptor_pool);
+        self.device.destroy_pipeline_layout(self.pipeline_layout);
+        self.device
+            .destroy_descriptor_set_layout(self.descriptor_set_layout);
+    }
+}
@@ -1,73 +1,370 @@
 use std::sync::Arc;
 
 use ash::vk;
+use eren_common::time_source::TimeSource;
 use eren_vulkan_render_shared::{
     command::CommandPool,
     device::{Device, FramebufferCreationError, RenderPassCreationError},
-    subpass::get_graphic_color_subpass_desc,
     swapchain::Swapchain,
 };
 use thiserror::Error;
 
-use crate::test_vertex_input::subpass::{TestSubpass, TestSubpassInitializationError};
+use crate::{
+    attachment::{DEPTH_FORMAT, ImageAttachment, ImageAttachmentCreationError},
+    capabilities::VulkanCapabilities,
+    debug_utils::{DebugUtils, RENDER_PASS_LABEL_COLOR},
+    offscreen::OffscreenTarget,
+    pipeline_cache::PipelineCache,
+    test_vertex_input::{
+        assets::TestAssets,
+        post_process::{PostProcessSubpass, PostProcessSubpassInitializationError},
+        subpass::{SubpassInfo, TestSubpass, TestSubpassInitializationError},
+    },
+};
 
-const CLEAR_VALUES: [vk::ClearValue; 1] = [vk::ClearValue {
-    color: vk::ClearColorValue {
-        float32: [0.1921, 0.302, 0.4745, 1.0],
+// attachment 0: 최종 출력(swapchain/offscreen), 1: 장면 color, 2: 장면 depth, 3: MSAA 장면 color
+// MSAA를 사용하지 않으면 attachment 3은 없고 남는 clear 값은 무시됨
+const CLEAR_VALUES: [vk::ClearValue; 4] = [
+    vk::ClearValue {
+        color: vk::ClearColorValue {
+            float32: [0.0, 0.0, 0.0, 1.0],
+        },
+    },
+    vk::ClearValue {
+        color: vk::ClearColorValue {
+            float32: [0.1921, 0.302, 0.4745, 1.0],
+        },
+    },
+    vk::ClearValue {
+        depth_stencil: vk::ClearDepthStencilValue {
+            depth: 1.0,
+            stencil: 0,
+        },
+    },
+    vk::ClearValue {
+        color: vk::ClearColorValue {
+            float32: [0.1921, 0.302, 0.4745, 1.0],
+        },
     },
-}];
+];
+
+const SCENE_SUBPASS: u32 = 0;
+const POST_PROCESS_SUBPASS: u32 = 1;
+
+// 렌더 패스의 color attachment가 될 대상
+pub enum RenderTarget<'a> {
+    Swapchain(&'a Swapchain),
+    Offscreen(&'a OffscreenTarget),
+}
+
+impl RenderTarget<'_> {
+    fn image_views(&self) -> Vec<vk::ImageView> {
+        match self {
+            RenderTarget::Swapchain(swapchain) => swapchain.image_views().to_vec(),
+            RenderTarget::Offscreen(offscreen) => vec![offscreen.image_view()],
+        }
+    }
+}
+
+// framebuffer마다 따로 할당되는 subpass 간 attachment
+pub struct FrameAttachments {
+    pub scene_color: ImageAttachment,
+    pub depth: ImageAttachment,
+    // MSAA 사용 시 장면을 그린 뒤 scene_color로 resolve
+    pub scene_color_msaa: Option<ImageAttachment>,
+}
+
+// framebuffer를 다시 만들 때 필요한 attachment 설정
+#[derive(Clone, Copy)]
+struct AttachmentConfig {
+    scene_color_format: vk::Format,
+    samples: vk::SampleCountFlags,
+    transient_memory: vk::MemoryPropertyFlags,
+}
 
 pub struct TestRenderPass {
     device: Arc<Device>,
+    debug_utils: DebugUtils,
     render_area: vk::Rect2D,
     render_pass: vk::RenderPass,
-    swapchain_framebuffers: Vec<vk::Framebuffer>,
+    attachment_config: AttachmentConfig,
+    framebuffers: Vec<vk::Framebuffer>,
+    attachments: Vec<FrameAttachments>,
     subpass: TestSubpass,
+    post_process_subpass: PostProcessSubpass,
 }
 
 #[derive(Debug, Error)]
 pub enum TestRenderPassInitializationError {
     #[error("Failed to create render pass: {0}")]
-    CreateRenderPass(#[from] RenderPassCreationError),
+    RenderPass(#[from] RenderPassCreationError),
+
+    #[error("Failed to create attachment: {0}")]
+    Attachment(#[from] ImageAttachmentCreationError),
 
     #[error("Failed to create framebuffers: {0}")]
-    CreateFramebuffers(#[from] FramebufferCreationError),
+    Framebuffers(#[from] FramebufferCreationError),
 
     #[error("Failed to create subpass: {0}")]
-    CreateSubpass(#[from] TestSubpassInitializationError),
+    Subpass(#[from] TestSubpassInitializationError),
+
+    #[error("Failed to create post process subpass: {0}")]
+    PostProcessSubpass(#[from] PostProcessSubpassInitializationError),
+}
+
+fn create_framebuffers(
+    device: &Arc<Device>,
+    render_pass: vk::RenderPass,
+    target_views: &[vk::ImageView],
+    config: AttachmentConfig,
+    extent: vk::Extent2D,
+    debug_utils: &DebugUtils,
+) -> Result<(Vec<vk::Framebuffer>, Vec<FrameAttachments>), TestRenderPassInitializationError> {
+    let mut framebuffers = Vec::with_capacity(target_views.len());
+    let mut attachments = Vec::with_capacity(target_views.len());
+
+    for (idx, &target_view) in target_views.iter().enumerate() {
+        // render pass 안에서만 사용되므로 store하지 않고 transient로 만들어 타일 메모리에 머물게 함
+        let frame_attachments = FrameAttachments {
+            scene_color: ImageAttachment::new(
+                device.clone(),
+                extent,
+                config.scene_color_format,
+                vk::SampleCountFlags::TYPE_1,
+                vk::ImageUsageFlags::COLOR_ATTACHMENT
+                    | vk::ImageUsageFlags::INPUT_ATTACHMENT
+                    | vk::ImageUsageFlags::TRANSIENT_ATTACHMENT,
+                vk::ImageAspectFlags::COLOR,
+                config.transient_memory,
+            )?,
+            depth: ImageAttachment::new(
+                device.clone(),
+                extent,
+                DEPTH_FORMAT,
+                config.samples,
+                vk::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT
+                    | vk::ImageUsageFlags::INPUT_ATTACHMENT
+                    | vk::ImageUsageFlags::TRANSIENT_ATTACHMENT,
+                vk::ImageAspectFlags::DEPTH,
+                config.transient_memory,
+            )?,
+            scene_color_msaa: if config.samples == vk::SampleCountFlags::TYPE_1 {
+                None
+            } else {
+                Some(ImageAttachment::new(
+                    device.clone(),
+                    extent,
+                    config.scene_color_format,
+                    config.samples,
+                    vk::ImageUsageFlags::COLOR_ATTACHMENT
+                        | vk::ImageUsageFlags::TRANSIENT_ATTACHMENT,
+                    vk::ImageAspectFlags::COLOR,
+                    config.transient_memory,
+                )?)
+            },
+        };
+
+        let mut views = vec![
+            target_view,
+            frame_attachments.scene_color.view(),
+            frame_attachments.depth.view(),
+        ];
+        if let Some(scene_color_msaa) = frame_attachments.scene_color_msaa.as_ref() {
+            views.push(scene_color_msaa.view());
+        }
+
+        let framebuffer =
+            match device.create_framebuffer(render_pass, &views, extent.width, extent.height) {
+                Ok(framebuffer) => framebuffer,
+                Err(e) => {
+                    for framebuffer in framebuffers {
+                        device.destroy_framebuffer(framebuffer);
+                    }
+                    return Err(e.into());
+                }
+            };
+
+        debug_utils.set_name(framebuffer, &format!("TestRenderPass framebuffer {}", idx));
+
+        framebuffers.push(framebuffer);
+        attachments.push(frame_attachments);
+    }
+
+    Ok((framebuffers, attachments))
 }
 
 impl TestRenderPass {
     pub fn new(
         device: Arc<Device>,
-        swapchain: &Swapchain,
+        target: RenderTarget,
+        assets: &TestAssets,
         command_pool: &CommandPool,
+        capabilities: &VulkanCapabilities,
         render_area: vk::Rect2D,
+        pipeline_cache: &PipelineCache,
     ) -> Result<Self, TestRenderPassInitializationError> {
-        let color_attachment = device.get_swapchain_color_attachment_desc();
-        let color_attachment_ref = device.get_color_attachment_ref(0);
+        let color_attachment = match target {
+            RenderTarget::Swapchain(_) => device.get_swapchain_color_attachment_desc(),
+            RenderTarget::Offscreen(offscreen) => offscreen.get_color_attachment_desc(),
+        };
+
+        // 후처리 subpass가 전체를 덮어쓰므로 최종 출력은 clear하지 않아도 됨
+        let color_attachment = color_attachment.load_op(vk::AttachmentLoadOp::DONT_CARE);
+
+        let samples = capabilities.sample_count;
+        let multisampled = samples != vk::SampleCountFlags::TYPE_1;
+
+        let attachment_config = AttachmentConfig {
+            scene_color_format: color_attachment.format,
+            samples,
+            transient_memory: capabilities.transient_memory_properties(),
+        };
+
+        // MSAA 사용 시 resolve 결과로 전체가 덮어써지므로 clear하지 않음
+        let scene_color_attachment = vk::AttachmentDescription2::default()
+            .format(attachment_config.scene_color_format)
+            .samples(vk::SampleCountFlags::TYPE_1)
+            .load_op(if multisampled {
+                vk::AttachmentLoadOp::DONT_CARE
+            } else {
+                vk::AttachmentLoadOp::CLEAR
+            })
+            .store_op(vk::AttachmentStoreOp::DONT_CARE)
+            .stencil_load_op(vk::AttachmentLoadOp::DONT_CARE)
+            .stencil_store_op(vk::AttachmentStoreOp::DONT_CARE)
+            .initial_layout(vk::ImageLayout::UNDEFINED)
+            .final_layout(vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL);
+
+        let depth_attachment = vk::AttachmentDescription2::default()
+            .format(DEPTH_FORMAT)
+            .samples(samples)
+            .load_op(vk::AttachmentLoadOp::CLEAR)
+            .store_op(vk::AttachmentStoreOp::DONT_CARE)
+            .stencil_load_op(vk::AttachmentLoadOp::DONT_CARE)
+            .stencil_store_op(vk::AttachmentStoreOp::DONT_CARE)
+            .initial_layout(vk::ImageLayout::UNDEFINED)
+            .final_layout(vk::ImageLayout::DEPTH_STENCIL_READ_ONLY_OPTIMAL);
+
+        let scene_color_msaa_attachment = vk::AttachmentDescription2::default()
+            .format(attachment_config.scene_color_format)
+            .samples(samples)
+            .load_op(vk::AttachmentLoadOp::CLEAR)
+            .store_op(vk::AttachmentStoreOp::DONT_CARE)
+            .stencil_load_op(vk::AttachmentLoadOp::DONT_CARE)
+            .stencil_store_op(vk::AttachmentStoreOp::DONT_CARE)
+            .initial_layout(vk::ImageLayout::UNDEFINED)
+            .final_layout(vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL);
+
+        // subpass 0: 장면을 attachment 1(color), 2(depth)에 렌더링
+        // MSAA 사용 시 attachment 3에 렌더링한 뒤 subpass 끝에서 attachment 1로 resolve
+        let scene_color_ref = vk::AttachmentReference2::default()
+            .attachment(1)
+            .layout(vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL)
+            .aspect_mask(vk::ImageAspectFlags::COLOR);
+        let scene_color_msaa_ref = vk::AttachmentReference2::default()
+            .attachment(3)
+            .layout(vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL)
+            .aspect_mask(vk::ImageAspectFlags::COLOR);
+
+        let scene_color_refs = [if multisampled {
+            scene_color_msaa_ref
+        } else {
+            scene_color_ref
+        }];
+        let scene_resolve_refs = [scene_color_ref];
+        let scene_depth_ref = vk::AttachmentReference2::default()
+            .attachment(2)
+            .layout(vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL)
+            .aspect_mask(vk::ImageAspectFlags::DEPTH);
 
-        let color_refs = [color_attachment_ref];
+        let mut scene_subpass = vk::SubpassDescription2::default()
+            .pipeline_bind_point(vk::PipelineBindPoint::GRAPHICS)
+            .color_attachments(&scene_color_refs)
+            .depth_stencil_attachment(&scene_depth_ref);
 
-        // subpass 0
-        let subpass = get_graphic_color_subpass_desc(&color_refs);
+        if multisampled {
+            scene_subpass = scene_subpass.resolve_attachments(&scene_resolve_refs);
+        }
+
+        // subpass 1: attachment 1, 2를 input attachment로 읽어 attachment 0에 출력
+        let input_refs = [
+            vk::AttachmentReference2::default()
+                .attachment(1)
+                .layout(vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL)
+                .aspect_mask(vk::ImageAspectFlags::COLOR),
+            vk::AttachmentReference2::default()
+                .attachment(2)
+                .layout(vk::ImageLayout::DEPTH_STENCIL_READ_ONLY_OPTIMAL)
+                .aspect_mask(vk::ImageAspectFlags::DEPTH),
+        ];
+        let output_refs = [device.get_color_attachment_ref(0)];
+
+        let post_process_subpass = vk::SubpassDescription2::default()
+            .pipeline_bind_point(vk::PipelineBindPoint::GRAPHICS)
+            .input_attachments(&input_refs)
+            .color_attachments(&output_refs);
+
+        let mut attachment_descs = vec![color_attachment, scene_color_attachment, depth_attachment];
+        if multisampled {
+            attachment_descs.push(scene_color_msaa_attachment);
+        }
 
-        let render_pass = device.create_render_pass(
-            &[color_attachment],
-            &[subpass],
+        // Vulkan 1.2 미만에서는 v1 구조체로 변환되어 생성됨
+        let render_pass = capabilities.create_render_pass(
+            &device,
+            &attachment_descs,
+            &[scene_subpass, post_process_subpass],
             &[
                 // external -> subpass 0
+                // 이전 프레임이 같은 depth/color attachment를 다 쓴 뒤에 clear
+                vk::SubpassDependency2::default()
+                    .src_subpass(vk::SUBPASS_EXTERNAL)
+                    .src_stage_mask(
+                        vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT
+                            | vk::PipelineStageFlags::LATE_FRAGMENT_TESTS,
+                    )
+                    .src_access_mask(vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_WRITE)
+                    .dst_subpass(SCENE_SUBPASS)
+                    .dst_stage_mask(
+                        vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT
+                            | vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS,
+                    )
+                    .dst_access_mask(
+                        vk::AccessFlags::COLOR_ATTACHMENT_WRITE
+                            | vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_WRITE,
+                    )
+                    .dependency_flags(vk::DependencyFlags::BY_REGION),
+                // external -> subpass 1
+                // 최종 출력은 subpass 1에서 처음 사용되므로, UNDEFINED에서의 layout 전환이
+                // acquire 세마포어 대기(COLOR_ATTACHMENT_OUTPUT) 이후에 일어나도록 함
                 vk::SubpassDependency2::default()
                     .src_subpass(vk::SUBPASS_EXTERNAL)
                     .src_stage_mask(vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT)
-                    .dst_subpass(0)
+                    .src_access_mask(vk::AccessFlags::empty())
+                    .dst_subpass(POST_PROCESS_SUBPASS)
                     .dst_stage_mask(vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT)
-                    .dst_access_mask(vk::AccessFlags::COLOR_ATTACHMENT_WRITE)
+                    .dst_access_mask(vk::AccessFlags::COLOR_ATTACHMENT_WRITE),
+                // subpass 0 -> subpass 1
+                // BY_REGION이므로 타일 단위로 바로 읽을 수 있음
+                vk::SubpassDependency2::default()
+                    .src_subpass(SCENE_SUBPASS)
+                    .src_stage_mask(
+                        vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT
+                            | vk::PipelineStageFlags::LATE_FRAGMENT_TESTS,
+                    )
+                    .src_access_mask(
+                        vk::AccessFlags::COLOR_ATTACHMENT_WRITE
+                            | vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_WRITE,
+                    )
+                    .dst_subpass(POST_PROCESS_SUBPASS)
+                    .dst_stage_mask(vk::PipelineStageFlags::FRAGMENT_SHADER)
+                    .dst_access_mask(vk::AccessFlags::INPUT_ATTACHMENT_READ)
                     .dependency_flags(vk::DependencyFlags::BY_REGION),
-                // subpass 0 -> external
+                // subpass 1 -> external
                 vk::SubpassDependency2::default()
-                    .src_subpass(0)
+                    .src_subpass(POST_PROCESS_SUBPASS)
                     .src_stage_mask(vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT)
+                    .src_access_mask(vk::AccessFlags::COLOR_ATTACHMENT_WRITE)
                     .dst_subpass(vk::SUBPASS_EXTERNAL)
                     .dst_stage_mask(vk::PipelineStageFlags::BOTTOM_OF_PIPE) // 가장 마지막 단계
                     .dst_access_mask(vk::AccessFlags::MEMORY_READ)
@@ -75,18 +372,97 @@ impl TestRenderPass {
             ],
         )?;
 
-        let swapchain_framebuffers = swapchain.create_framebuffers(render_pass)?;
-        let subpass = TestSubpass::new(device.clone(), command_pool, render_area, render_pass, 0)?;
+        let debug_utils = capabilities.debug_utils.clone();
+        debug_utils.set_name(render_pass, "TestRenderPass");
+
+        let (framebuffers, attachments) = create_framebuffers(
+            &device,
+            render_pass,
+            &target.image_views(),
+            attachment_config,
+            render_area.extent,
+            &debug_utils,
+        )?;
+
+        let subpass = TestSubpass::new(
+            device.clone(),
+            command_pool,
+            render_area,
+            SubpassInfo {
+                render_pass,
+                index: SCENE_SUBPASS,
+                pipeline_cache: pipeline_cache.handle(),
+            },
+            capabilities,
+            assets,
+        )?;
+        let post_process_subpass = PostProcessSubpass::new(
+            device.clone(),
+            render_area,
+            SubpassInfo {
+                render_pass,
+                index: POST_PROCESS_SUBPASS,
+                pipeline_cache: pipeline_cache.handle(),
+            },
+            capabilities,
+            &attachments,
+            assets,
+        )?;
+
+        // 다음 실행에서 재사용하도록 새로 컴파일된 파이프라인을 저장
+        pipeline_cache.save();
 
         Ok(Self {
             device,
+            debug_utils,
             render_area,
             render_pass,
-            swapchain_framebuffers,
+            attachment_config,
+            framebuffers,
+            attachments,
             subpass,
+            post_process_subpass,
         })
     }
 
+    pub fn set_time_source(&mut self, time_source: Box<dyn TimeSource>) {
+        self.subpass.set_time_source(time_source);
+    }
+
+    // swapchain 이미지에 묶인 framebuffer, attachment와 render area만 갱신하고
+    // 렌더 패스, 파이프라인, 버퍼, descriptor set은 그대로 유지
+    pub fn on_swapchain_recreated(
+        &mut self,
+        swapchain: &Swapchain,
+        render_area: vk::Rect2D,
+    ) -> Result<(), TestRenderPassInitializationError> {
+        // 이전 framebuffer를 사용하는 명령이 끝날 때까지 대기
+        self.device.wait_idle();
+
+        for framebuffer in self.framebuffers.drain(..) {
+            self.device.destroy_framebuffer(framebuffer);
+        }
+        self.attachments.clear();
+
+        let (framebuffers, attachments) = create_framebuffers(
+            &self.device,
+            self.render_pass,
+            swapchain.image_views(),
+            self.attachment_config,
+            render_area.extent,
+            &self.debug_utils,
+        )?;
+
+        self.framebuffers = framebuffers;
+        self.attachments = attachments;
+        self.render_area = render_area;
+        self.subpass.on_swapchain_recreated(render_area);
+        self.post_process_subpass
+            .on_swapchain_recreated(render_area, &self.attachments)?;
+
+        Ok(())
+    }
+
     pub fn record_commands(
         &mut self,
         command_buffer: vk::CommandBuffer,
@@ -96,10 +472,13 @@ impl TestRenderPass {
         window_height: u32,
         pre_transform: vk::SurfaceTransformFlagsKHR,
     ) {
+        self.debug_utils
+            .begin_label(command_buffer, "TestRenderPass", RENDER_PASS_LABEL_COLOR);
+
         self.device.begin_render_pass(
             command_buffer,
             self.render_pass,
-            self.swapchain_framebuffers[swapchain_image_idx],
+            self.framebuffers[swapchain_image_idx],
             self.render_area,
             &CLEAR_VALUES,
         );
@@ -111,9 +490,15 @@ impl TestRenderPass {
             window_height,
             pre_transform,
         );
-        //self.device.next_subpass(command_buffer); 다음 subpass로 넘어가려면 필요
+
+        self.device.next_subpass(command_buffer);
+
+        self.post_process_subpass
+            .record_commands(command_buffer, swapchain_image_idx);
 
         self.device.end_render_pass(command_buffer);
+
+        self.debug_utils.end_label(command_buffer);
     }
 }
 
@@ -121,7 +506,7 @@ impl Drop for TestRenderPass {
     fn drop(&mut self) {
         self.device.wait_idle();
 
-        for &framebuffer in self.swapchain_framebuffers.iter() {
+        for &framebuffer in self.framebuffers.iter() {
             self.device.destroy_framebuffer(framebuffer);
         }
 
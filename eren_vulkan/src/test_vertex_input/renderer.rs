@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use ash::vk;
+use eren_common::time_source::TimeSource;
 use eren_vulkan_render_shared::{
     command::CommandPool,
     device::{
@@ -12,7 +13,15 @@ use eren_vulkan_render_shared::{
 };
 use thiserror::Error;
 
-use crate::test_vertex_input::render_pass::{TestRenderPass, TestRenderPassInitializationError};
+use crate::{
+    capabilities::VulkanCapabilities,
+    offscreen::{OffscreenTarget, ReadPixelsError},
+    pipeline_cache::PipelineCache,
+    test_vertex_input::{
+        assets::TestAssets,
+        render_pass::{RenderTarget, TestRenderPass, TestRenderPassInitializationError},
+    },
+};
 
 pub struct TestRenderer {
     device: Arc<Device>,
@@ -57,16 +66,51 @@ pub enum RenderError {
     Present(#[from] SwapchainPresentError),
 }
 
+impl RenderError {
+    // 에러를 일으킨 Vulkan 결과 코드
+    pub fn vk_result(&self) -> Option<vk::Result> {
+        match self {
+            RenderError::WaitForFences(WaitForFencesError::WaitForFences(result))
+            | RenderError::AcquireNextImage(SwapchainAcquireError::AcquireNextImage(result))
+            | RenderError::SubmitGraphicsCommands(SubmitGraphicsCommandsError::QueueSubmit(
+                result,
+            ))
+            | RenderError::Present(SwapchainPresentError::QueuePresent(result)) => Some(*result),
+            _ => None,
+        }
+    }
+
+    // swapchain을 다시 만들어야 하는 경우
+    pub fn is_out_of_date(&self) -> bool {
+        self.vk_result() == Some(vk::Result::ERROR_OUT_OF_DATE_KHR)
+    }
+
+    // instance부터 모든 리소스를 다시 만들어야 하는 경우
+    pub fn is_device_lost(&self) -> bool {
+        self.vk_result() == Some(vk::Result::ERROR_DEVICE_LOST)
+    }
+}
+
 impl TestRenderer {
     pub fn new(
         device: Arc<Device>,
         swapchain: Arc<Swapchain>,
+        assets: &TestAssets,
         command_pool: &CommandPool,
+        capabilities: &VulkanCapabilities,
         render_area: vk::Rect2D,
+        pipeline_cache: &PipelineCache,
     ) -> Result<Self, TestRendererInitializationError> {
         let frame_mgr = FrameManager::new(device.clone(), command_pool, swapchain.image_len)?;
-        let render_pass =
-            TestRenderPass::new(device.clone(), &swapchain, command_pool, render_area)?;
+        let render_pass = TestRenderPass::new(
+            device.clone(),
+            RenderTarget::Swapchain(&swapchain),
+            assets,
+            command_pool,
+            capabilities,
+            render_area,
+            pipeline_cache,
+        )?;
 
         Ok(Self {
             device,
@@ -76,6 +120,25 @@ impl TestRenderer {
         })
     }
 
+    // 새 swapchain에 맞춰 framebuffer, 이미지별 동기화 객체, render area만 갱신
+    pub fn on_swapchain_recreated(
+        &mut self,
+        swapchain: Arc<Swapchain>,
+        command_pool: &CommandPool,
+        render_area: vk::Rect2D,
+    ) -> Result<(), TestRendererInitializationError> {
+        self.render_pass
+            .on_swapchain_recreated(&swapchain, render_area)?;
+
+        // suboptimal로 획득만 하고 제출하지 않은 이미지의 세마포어가 signal 상태로 남을 수 있고
+        // 이미지 개수도 바뀔 수 있으므로 동기화 객체는 새로 만듦
+        self.frame_mgr = FrameManager::new(self.device.clone(), command_pool, swapchain.image_len)?;
+
+        self.swapchain = swapchain;
+
+        Ok(())
+    }
+
     pub fn render(&mut self) -> Result<bool, RenderError> {
         let (frame, frame_idx) = self.frame_mgr.next_frame();
         let (image_available, in_flight, cmd_buffer) =
@@ -83,7 +146,6 @@ impl TestRenderer {
 
         // 이전 프레임 GPU 작업 완료 대기
         self.device.wait_for_fence(in_flight)?;
-        self.device.reset_fence(in_flight)?;
 
         let (swapchain_image_idx, is_suboptimal) = self.swapchain.acquire_next_image(
             image_available, // wait
@@ -94,6 +156,9 @@ impl TestRenderer {
             return Ok(true);
         }
 
+        // 이미지 획득에 실패하면 fence가 signal되지 않으므로 획득 이후에 reset
+        self.device.reset_fence(in_flight)?;
+
         // 이미지 전용 세마포어 가져오기
         let img = self.frame_mgr.swapchain_image(swapchain_image_idx as usize);
 
@@ -129,3 +194,99 @@ impl TestRenderer {
         Ok(is_suboptimal)
     }
 }
+
+#[derive(Debug, Error)]
+pub enum OffscreenRenderError {
+    #[error("Failed to wait for fences: {0}")]
+    WaitForFences(#[from] WaitForFencesError),
+
+    #[error("Failed to reset fences: {0}")]
+    ResetFences(#[from] ResetFencesError),
+
+    #[error("Failed to reset command buffer: {0}")]
+    ResetCommandBuffer(#[from] CommandBufferResetError),
+
+    #[error("Failed to begin command buffer: {0}")]
+    BeginCommandBuffer(#[from] CommandBufferBeginError),
+
+    #[error("Failed to end command buffer: {0}")]
+    EndCommandBuffer(#[from] CommandBufferEndError),
+
+    #[error("Failed to submit commands: {0}")]
+    SubmitCommands(#[from] SubmitGraphicsCommandsError),
+
+    #[error("Failed to read pixels: {0}")]
+    ReadPixels(#[from] ReadPixelsError),
+}
+
+// Swapchain 없이 OffscreenTarget에 렌더링하는 renderer
+pub struct TestOffscreenRenderer {
+    device: Arc<Device>,
+    command_pool: Arc<CommandPool>,
+    frame_mgr: FrameManager,
+    render_pass: TestRenderPass,
+}
+
+impl TestOffscreenRenderer {
+    pub fn new(
+        device: Arc<Device>,
+        command_pool: Arc<CommandPool>,
+        capabilities: &VulkanCapabilities,
+        target: &OffscreenTarget,
+        assets: &TestAssets,
+        pipeline_cache: &PipelineCache,
+    ) -> Result<Self, TestRendererInitializationError> {
+        let frame_mgr = FrameManager::new(device.clone(), &command_pool, 1)?;
+        let render_pass = TestRenderPass::new(
+            device.clone(),
+            RenderTarget::Offscreen(target),
+            assets,
+            &command_pool,
+            capabilities,
+            vk::Rect2D {
+                offset: vk::Offset2D::default(),
+                extent: target.extent(),
+            },
+            pipeline_cache,
+        )?;
+
+        Ok(Self {
+            device,
+            command_pool,
+            frame_mgr,
+            render_pass,
+        })
+    }
+
+    pub fn set_time_source(&mut self, time_source: Box<dyn TimeSource>) {
+        self.render_pass.set_time_source(time_source);
+    }
+
+    pub fn render(&mut self, target: &OffscreenTarget) -> Result<Vec<u8>, OffscreenRenderError> {
+        let (frame, frame_idx) = self.frame_mgr.next_frame();
+        let (in_flight, cmd_buffer) = { (frame.in_flight, frame.cmd_buffer) };
+
+        self.device.wait_for_fence(in_flight)?;
+        self.device.reset_fence(in_flight)?;
+
+        self.device.reset_command_buffer(cmd_buffer)?;
+        self.device.begin_command_buffer(cmd_buffer)?;
+
+        self.render_pass.record_commands(
+            cmd_buffer,
+            0,
+            frame_idx,
+            target.width,
+            target.height,
+            vk::SurfaceTransformFlagsKHR::IDENTITY,
+        );
+
+        self.device.end_command_buffer(cmd_buffer)?;
+
+        // 표시할 swapchain 이미지가 없으므로 semaphore 없이 제출
+        self.device.submit_commands(cmd_buffer, in_flight)?;
+        self.device.wait_for_fence(in_flight)?;
+
+        Ok(target.read_pixels(&self.command_pool)?)
+    }
+}
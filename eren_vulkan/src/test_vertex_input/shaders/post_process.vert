@@ -0,0 +1,7 @@
+#version 450
+
+// 정점 버퍼 없이 화면 전체를 덮는 삼각형 (0,0), (2,0), (0,2)
+void main() {
+    vec2 uv = vec2((gl_VertexIndex << 1) & 2, gl_VertexIndex & 2);
+    gl_Position = vec4(uv * 2.0 - 1.0, 0.0, 1.0);
+}
@@ -0,0 +1,17 @@
+#version 450
+
+layout(input_attachment_index = 0, set = 0, binding = 0) uniform subpassInput inputColor;
+layout(input_attachment_index = 1, set = 0, binding = 1) uniform subpassInputMS inputDepth;
+
+layout(location = 0) out vec4 outColor;
+
+void main() {
+    vec3 color = subpassLoad(inputColor).rgb;
+    float depth = subpassLoad(inputDepth, 0).r;
+
+    // MSAA depth는 resolve되지 않으므로 첫 번째 샘플을 사용
+    // 배경(depth 1.0)은 그대로 두고 멀리 있는 물체일수록 어둡게 표시
+    float shade = depth < 1.0 ? mix(1.0, 0.6, smoothstep(0.95, 1.0, depth)) : 1.0;
+
+    outColor = vec4(color * shade, 1.0);
+}
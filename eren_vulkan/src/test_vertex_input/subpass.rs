@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
-use glam::{Vec2, Vec3};
-
 use ash::vk;
+use eren_common::time_source::{SystemTimeSource, TimeSource};
+use eren_vertex::VkVertexLayout;
 use eren_vulkan_render_shared::{
     command::CommandPool,
     device::{
@@ -16,31 +16,16 @@ use eren_vulkan_render_shared::{
 };
 use thiserror::Error;
 
-use crate::test_vertex_input::{ubo::UniformBufferObject, vertex::Vertex};
-
-const VERT_SHADER_BYTES: &[u8] = include_bytes!("./shaders/shader.vert.spv");
-const FRAG_SHADER_BYTES: &[u8] = include_bytes!("./shaders/shader.frag.spv");
-
-const TEST_VERTICES: [Vertex; 4] = [
-    Vertex {
-        pos: Vec2::new(-0.5, -0.5),
-        color: Vec3::new(1.0, 0.0, 0.0),
-    },
-    Vertex {
-        pos: Vec2::new(0.5, -0.5),
-        color: Vec3::new(0.0, 1.0, 0.0),
+use crate::{
+    capabilities::VulkanCapabilities,
+    debug_utils::{DebugUtils, SCENE_LABEL_COLOR},
+    shader_reflection::{PipelineReflection, ShaderReflectionError},
+    test_vertex_input::{
+        assets::TestAssets, mesh::MeshIndices, ubo::UniformBufferObject, vertex::Vertex,
     },
-    Vertex {
-        pos: Vec2::new(0.5, 0.5),
-        color: Vec3::new(0.0, 0.0, 1.0),
-    },
-    Vertex {
-        pos: Vec2::new(-0.5, 0.5),
-        color: Vec3::new(1.0, 1.0, 1.0),
-    },
-];
-
-const TEST_INDICES: [u16; 6] = [0, 1, 2, 2, 3, 0];
+    texture::{Texture, TextureCreationError},
+    typed_buffer::{UniformBuffer, UniformBufferCreationError},
+};
 
 #[derive(Debug, Error)]
 pub enum BufferCreationError {
@@ -59,15 +44,21 @@ pub struct CombinedBuffer {
     pub memory: vk::DeviceMemory,
     pub vertex_offset: vk::DeviceSize,
     pub index_offset: vk::DeviceSize,
+    pub index_type: vk::IndexType,
     pub index_count: u32,
 }
 
 pub fn create_combined_buffer(
     device: &Device,
     command_pool: &CommandPool,
+    vertices: &[Vertex],
+    indices: &MeshIndices,
+    debug_utils: &DebugUtils,
 ) -> Result<CombinedBuffer, BufferCreationError> {
-    let vertex_size = (std::mem::size_of::<Vertex>() * TEST_VERTICES.len()) as vk::DeviceSize;
-    let index_size = (std::mem::size_of::<u16>() * TEST_INDICES.len()) as vk::DeviceSize;
+    let index_bytes = indices.as_bytes();
+
+    let vertex_size = std::mem::size_of_val(vertices) as vk::DeviceSize;
+    let index_size = index_bytes.len() as vk::DeviceSize;
 
     let index_offset = (vertex_size + 3) & !3;
     let total_size = index_offset + index_size;
@@ -77,20 +68,9 @@ pub fn create_combined_buffer(
         vk::BufferUsageFlags::TRANSFER_SRC,
         vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT,
     )?;
+    debug_utils.set_name(staging_buffer, "TestSubpass vertex/index staging buffer");
 
-    let vertex_bytes = unsafe {
-        std::slice::from_raw_parts(
-            TEST_VERTICES.as_ptr() as *const u8,
-            TEST_VERTICES.len() * std::mem::size_of::<Vertex>(),
-        )
-    };
-
-    let index_bytes = unsafe {
-        std::slice::from_raw_parts(
-            TEST_INDICES.as_ptr() as *const u8,
-            TEST_INDICES.len() * std::mem::size_of::<u16>(),
-        )
-    };
+    let vertex_bytes: &[u8] = bytemuck::cast_slice(vertices);
 
     let slices = [
         MemoryUploadSlice {
@@ -112,6 +92,7 @@ pub fn create_combined_buffer(
             | vk::BufferUsageFlags::INDEX_BUFFER,
         vk::MemoryPropertyFlags::DEVICE_LOCAL,
     )?;
+    debug_utils.set_name(buffer, "TestSubpass vertex/index buffer");
 
     command_pool.copy_buffer(staging_buffer, buffer, total_size)?;
     device.destroy_buffer_with_memory(staging_buffer, staging_memory);
@@ -121,25 +102,131 @@ pub fn create_combined_buffer(
         memory,
         vertex_offset: 0,
         index_offset,
-        index_count: TEST_INDICES.len() as u32,
+        index_type: indices.index_type(),
+        index_count: indices.len() as u32,
     })
 }
 
+// 파이프라인을 만들 render pass, subpass 위치와 파이프라인 캐시
+#[derive(Clone, Copy)]
+pub struct SubpassInfo {
+    pub render_pass: vk::RenderPass,
+    pub index: u32,
+    pub pipeline_cache: vk::PipelineCache,
+}
+
+// viewport/scissor는 dynamic state로 두어 화면 크기가 바뀌어도 파이프라인을 유지
+fn create_pipeline(
+    device: &Arc<Device>,
+    pipeline_layout: vk::PipelineLayout,
+    subpass: SubpassInfo,
+    samples: vk::SampleCountFlags,
+    assets: &TestAssets,
+) -> Result<GraphicsPipeline, GraphicsPipelineCreationError> {
+    let binding_descriptions = [Vertex::BINDING_DESCRIPTION];
+
+    let vertex_input_info = vk::PipelineVertexInputStateCreateInfo::default()
+        .vertex_binding_descriptions(&binding_descriptions)
+        .vertex_attribute_descriptions(Vertex::ATTRIBUTE_DESCRIPTIONS);
+
+    let input_assembly = vk::PipelineInputAssemblyStateCreateInfo::default()
+        .topology(vk::PrimitiveTopology::TRIANGLE_LIST)
+        .primitive_restart_enable(false);
+
+    // 실제 값은 record_commands에서 설정
+    let viewport_state = vk::PipelineViewportStateCreateInfo::default()
+        .viewport_count(1)
+        .scissor_count(1);
+
+    let dynamic_states = [vk::DynamicState::VIEWPORT, vk::DynamicState::SCISSOR];
+    let dynamic_state =
+        vk::PipelineDynamicStateCreateInfo::default().dynamic_states(&dynamic_states);
+
+    let rasterizer = vk::PipelineRasterizationStateCreateInfo::default()
+        .depth_clamp_enable(false)
+        .rasterizer_discard_enable(false)
+        .polygon_mode(vk::PolygonMode::FILL)
+        .line_width(1.0)
+        .cull_mode(vk::CullModeFlags::BACK)
+        .front_face(vk::FrontFace::COUNTER_CLOCKWISE)
+        .depth_bias_enable(false)
+        .depth_bias_constant_factor(0.0) // Optional
+        .depth_bias_clamp(0.0) // Optional
+        .depth_bias_slope_factor(0.0); // Optional
+
+    let multisampling = vk::PipelineMultisampleStateCreateInfo::default()
+        .sample_shading_enable(false)
+        .rasterization_samples(samples)
+        .min_sample_shading(1.0) // Optional
+        .sample_mask(&[]) // Optional
+        .alpha_to_coverage_enable(false) // Optional
+        .alpha_to_one_enable(false); // Optional
+
+    let depth_stencil = vk::PipelineDepthStencilStateCreateInfo::default()
+        .depth_test_enable(true)
+        .depth_write_enable(true)
+        .depth_compare_op(vk::CompareOp::LESS)
+        .depth_bounds_test_enable(false)
+        .stencil_test_enable(false);
+
+    // 텍스처의 투명한 부분이 배경과 섞이도록 알파 블렌딩 (wgpu의 ALPHA_BLENDING과 동일)
+    let color_blend_attachment = vk::PipelineColorBlendAttachmentState::default()
+        .color_write_mask(vk::ColorComponentFlags::RGBA)
+        .blend_enable(true)
+        .src_color_blend_factor(vk::BlendFactor::SRC_ALPHA)
+        .dst_color_blend_factor(vk::BlendFactor::ONE_MINUS_SRC_ALPHA)
+        .color_blend_op(vk::BlendOp::ADD)
+        .src_alpha_blend_factor(vk::BlendFactor::ONE)
+        .dst_alpha_blend_factor(vk::BlendFactor::ONE_MINUS_SRC_ALPHA)
+        .alpha_blend_op(vk::BlendOp::ADD);
+
+    let color_blend_attachment_states = [color_blend_attachment];
+    let color_blending = vk::PipelineColorBlendStateCreateInfo::default()
+        .logic_op_enable(false)
+        .logic_op(vk::LogicOp::COPY) // Optional
+        .attachments(&color_blend_attachment_states)
+        .blend_constants([0.0, 0.0, 0.0, 0.0]); // Optional
+
+    let pipeline_info = vk::GraphicsPipelineCreateInfo::default()
+        .vertex_input_state(&vertex_input_info)
+        .input_assembly_state(&input_assembly)
+        .viewport_state(&viewport_state)
+        .dynamic_state(&dynamic_state)
+        .rasterization_state(&rasterizer)
+        .multisample_state(&multisampling)
+        .depth_stencil_state(&depth_stencil)
+        .color_blend_state(&color_blending)
+        .layout(pipeline_layout)
+        .render_pass(subpass.render_pass)
+        .subpass(subpass.index);
+
+    GraphicsPipeline::with_cache(
+        device.clone(),
+        pipeline_info,
+        subpass.pipeline_cache,
+        Some(&assets.vert_shader),
+        Some(&assets.frag_shader),
+    )
+}
+
 pub struct TestSubpass {
     device: Arc<Device>,
+    debug_utils: DebugUtils,
+
+    render_area: vk::Rect2D,
 
     descriptor_set_layout: vk::DescriptorSetLayout,
     pipeline_layout: vk::PipelineLayout,
     pipeline: GraphicsPipeline,
 
     combined_buffer: CombinedBuffer,
-    uniform_buffers: Vec<vk::Buffer>,
-    uniform_buffers_memory: Vec<vk::DeviceMemory>,
-    uniform_buffers_mapped: Vec<*mut std::ffi::c_void>,
+    // descriptor set이 참조하므로 subpass와 함께 유지
+    _texture: Texture,
+    uniform_buffers: Vec<UniformBuffer<UniformBufferObject>>,
     descriptor_pool: vk::DescriptorPool,
     descriptor_sets: Vec<vk::DescriptorSet>,
 
-    start_time: std::time::Instant,
+    time_source: Box<dyn TimeSource>,
 }
 
 #[derive(Debug, Error)]
@@ -156,17 +243,38 @@ pub enum TestSubpassInitializationError {
     #[error("Failed to create buffer: {0}")]
     CreateBuffer(#[from] BufferCreationError),
 
-    #[error("Failed to create buffer with memory: {0}")]
-    CreateBufferWithMemory(#[from] BufferWithMemoryCreationError),
+    #[error("Failed to reflect shaders: {0}")]
+    ReflectShaders(#[from] ShaderReflectionError),
 
-    #[error("Failed to map memory: {0}")]
-    MapMemory(#[from] MapMemoryError),
+    #[error("Failed to create uniform buffer: {0}")]
+    CreateUniformBuffer(#[from] UniformBufferCreationError),
 
     #[error("Failed to create descriptor pool: {0}")]
     CreateDescriptorPool(#[from] DescriptorPoolCreationError),
 
     #[error("Failed to allocate descriptor sets: {0}")]
     AllocateDescriptorSets(#[from] DescriptorSetAllocationError),
+
+    #[error("Failed to create texture: {0}")]
+    CreateTexture(#[from] TextureCreationError),
+}
+
+fn create_scene_texture(
+    device: &Arc<Device>,
+    command_pool: &CommandPool,
+    assets: &TestAssets,
+    debug_utils: &DebugUtils,
+) -> Result<Texture, TestSubpassInitializationError> {
+    let Some(bytes) = assets.texture.as_deref() else {
+        return Ok(Texture::white(device.clone(), command_pool, debug_utils)?);
+    };
+
+    Ok(Texture::from_image_bytes(
+        device.clone(),
+        command_pool,
+        bytes,
+        debug_utils,
+    )?)
 }
 
 impl TestSubpass {
@@ -174,178 +282,136 @@ impl TestSubpass {
         device: Arc<Device>,
         command_pool: &CommandPool,
         render_area: vk::Rect2D,
-        render_pass: vk::RenderPass,
-        subpass_index: u32,
+        subpass: SubpassInfo,
+        capabilities: &VulkanCapabilities,
+        assets: &TestAssets,
     ) -> Result<Self, TestSubpassInitializationError> {
-        let ubo_layout_binding = vk::DescriptorSetLayoutBinding::default()
-            .binding(0)
-            .descriptor_type(vk::DescriptorType::UNIFORM_BUFFER)
-            .descriptor_count(1)
-            .stage_flags(vk::ShaderStageFlags::VERTEX);
-
-        let descriptor_set_layout = device.create_descriptor_set_layout(&[ubo_layout_binding])?;
-
-        let pipeline_layout = device.create_pipeline_layout(&[descriptor_set_layout], &[])?;
+        let debug_utils = capabilities.debug_utils.clone();
 
-        let binding_descriptions = [Vertex::get_binding_description()];
-        let attribute_descriptions = Vertex::get_attribute_descriptions();
+        // descriptor set layout은 셰이더에서 읽고, Rust 쪽 Vertex/UBO 레이아웃이 셰이더와 맞는지 확인
+        let reflection =
+            PipelineReflection::new(&[assets.vert_shader.as_slice(), &assets.frag_shader])?;
+        reflection.validate_vertex_input(Vertex::ATTRIBUTE_DESCRIPTIONS)?;
+        reflection.validate_uniform_buffer::<UniformBufferObject>(0, 0)?;
 
-        let vertex_input_info = vk::PipelineVertexInputStateCreateInfo::default()
-            .vertex_binding_descriptions(&binding_descriptions)
-            .vertex_attribute_descriptions(&attribute_descriptions);
+        let bindings = reflection.descriptor_set_layout_bindings(0);
+        let descriptor_set_layout = device.create_descriptor_set_layout(&bindings)?;
 
-        let input_assembly = vk::PipelineInputAssemblyStateCreateInfo::default()
-            .topology(vk::PrimitiveTopology::TRIANGLE_LIST)
-            .primitive_restart_enable(false);
+        let pipeline_layout = device
+            .create_pipeline_layout(&[descriptor_set_layout], &reflection.push_constant_ranges())?;
 
-        let viewport = vk::Viewport {
-            x: 0.0,
-            y: 0.0,
-            width: render_area.extent.width as f32,
-            height: render_area.extent.height as f32,
-            min_depth: 0.0,
-            max_depth: 1.0,
-        };
-
-        let viewports = [viewport];
-
-        let scissor = vk::Rect2D {
-            offset: vk::Offset2D { x: 0, y: 0 },
-            extent: render_area.extent,
-        };
-
-        let scissors = [scissor];
-
-        let viewport_state = vk::PipelineViewportStateCreateInfo::default()
-            .viewports(&viewports)
-            .scissors(&scissors);
-
-        let rasterizer = vk::PipelineRasterizationStateCreateInfo::default()
-            .depth_clamp_enable(false)
-            .rasterizer_discard_enable(false)
-            .polygon_mode(vk::PolygonMode::FILL)
-            .line_width(1.0)
-            .cull_mode(vk::CullModeFlags::BACK)
-            .front_face(vk::FrontFace::COUNTER_CLOCKWISE)
-            .depth_bias_enable(false)
-            .depth_bias_constant_factor(0.0) // Optional
-            .depth_bias_clamp(0.0) // Optional
-            .depth_bias_slope_factor(0.0); // Optional
-
-        let multisampling = vk::PipelineMultisampleStateCreateInfo::default()
-            .sample_shading_enable(false)
-            .rasterization_samples(vk::SampleCountFlags::TYPE_1)
-            .min_sample_shading(1.0) // Optional
-            .sample_mask(&[]) // Optional
-            .alpha_to_coverage_enable(false) // Optional
-            .alpha_to_one_enable(false); // Optional
-
-        let color_blend_attachment = vk::PipelineColorBlendAttachmentState::default()
-            .color_write_mask(vk::ColorComponentFlags::RGBA)
-            .blend_enable(false)
-            .src_color_blend_factor(vk::BlendFactor::ONE) // Optional
-            .dst_color_blend_factor(vk::BlendFactor::ZERO) // Optional
-            .color_blend_op(vk::BlendOp::ADD) // Optional
-            .src_alpha_blend_factor(vk::BlendFactor::ONE) // Optional
-            .dst_alpha_blend_factor(vk::BlendFactor::ZERO) // Optional
-            .alpha_blend_op(vk::BlendOp::ADD); // Optional
-
-        let color_blend_attachment_states = [color_blend_attachment];
-        let color_blending = vk::PipelineColorBlendStateCreateInfo::default()
-            .logic_op_enable(false)
-            .logic_op(vk::LogicOp::COPY) // Optional
-            .attachments(&color_blend_attachment_states)
-            .blend_constants([0.0, 0.0, 0.0, 0.0]); // Optional
-
-        let pipeline_info = vk::GraphicsPipelineCreateInfo::default()
-            .vertex_input_state(&vertex_input_info)
-            .input_assembly_state(&input_assembly)
-            .viewport_state(&viewport_state)
-            .rasterization_state(&rasterizer)
-            .multisample_state(&multisampling)
-            .color_blend_state(&color_blending)
-            .layout(pipeline_layout)
-            .render_pass(render_pass)
-            .subpass(subpass_index);
-
-        let pipeline = GraphicsPipeline::new(
-            device.clone(),
-            pipeline_info,
-            Some(VERT_SHADER_BYTES),
-            Some(FRAG_SHADER_BYTES),
+        let pipeline = create_pipeline(
+            &device,
+            pipeline_layout,
+            subpass,
+            capabilities.sample_count,
+            assets,
         )?;
+        debug_utils.set_name(pipeline.handle(), "TestSubpass pipeline");
+
+        let combined_buffer = create_combined_buffer(
+            &device,
+            command_pool,
+            &assets.mesh.vertices,
+            &assets.mesh.indices,
+            &debug_utils,
+        )?;
+        let texture = create_scene_texture(&device, command_pool, assets, &debug_utils)?;
 
-        let combined_buffer = create_combined_buffer(&device, command_pool)?;
-
-        let buffer_size = std::mem::size_of::<UniformBufferObject>() as vk::DeviceSize;
-
-        let mut uniform_buffers = Vec::new();
-        let mut uniform_buffers_memory = Vec::new();
-        let mut uniform_buffers_mapped = Vec::new();
-
-        uniform_buffers.resize(MAX_FRAMES_IN_FLIGHT, vk::Buffer::null());
-        uniform_buffers_memory.resize(MAX_FRAMES_IN_FLIGHT, vk::DeviceMemory::null());
-        uniform_buffers_mapped.resize(MAX_FRAMES_IN_FLIGHT, std::ptr::null_mut());
+        let mut uniform_buffers = Vec::with_capacity(MAX_FRAMES_IN_FLIGHT);
 
         let descriptor_pool = device.create_descriptor_pool(
             MAX_FRAMES_IN_FLIGHT as u32,
-            &[vk::DescriptorPoolSize {
-                ty: vk::DescriptorType::UNIFORM_BUFFER,
-                descriptor_count: MAX_FRAMES_IN_FLIGHT as u32,
-            }],
+            &reflection.descriptor_pool_sizes(0, MAX_FRAMES_IN_FLIGHT as u32),
         )?;
 
         let descriptor_set_layouts = vec![descriptor_set_layout; MAX_FRAMES_IN_FLIGHT];
         let descriptor_sets =
             device.allocate_descriptor_sets(descriptor_pool, &descriptor_set_layouts)?;
 
-        for i in 0..MAX_FRAMES_IN_FLIGHT {
-            let (buffer, memory) = device.create_buffer_with_memory(
-                buffer_size,
-                vk::BufferUsageFlags::UNIFORM_BUFFER,
-                vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT,
-            )?;
+        for (idx, &descriptor_set) in descriptor_sets.iter().enumerate() {
+            let uniform_buffer = UniformBuffer::<UniformBufferObject>::new(device.clone())?;
 
-            uniform_buffers[i] = buffer;
-            uniform_buffers_memory[i] = memory;
-            uniform_buffers_mapped[i] =
-                device.map_memory(uniform_buffers_memory[i], buffer_size)?;
+            debug_utils.set_name(
+                uniform_buffer.buffer(),
+                &format!("TestSubpass uniform buffer {}", idx),
+            );
+            debug_utils.set_name(
+                descriptor_set,
+                &format!("TestSubpass descriptor set {}", idx),
+            );
 
             let buffer_info = vk::DescriptorBufferInfo {
-                buffer,
+                buffer: uniform_buffer.buffer(),
                 offset: 0,
-                range: std::mem::size_of::<UniformBufferObject>() as vk::DeviceSize,
+                range: UniformBuffer::<UniformBufferObject>::size(),
             };
 
             let buffer_infos = [buffer_info];
-            let descriptor_write = vk::WriteDescriptorSet::default()
-                .dst_set(descriptor_sets[i])
-                .dst_binding(0)
-                .dst_array_element(0)
-                .descriptor_type(vk::DescriptorType::UNIFORM_BUFFER)
-                .buffer_info(&buffer_infos);
-
-            device.write_descriptor_sets(&[descriptor_write]);
+            let image_infos = [vk::DescriptorImageInfo {
+                sampler: vk::Sampler::null(),
+                image_view: texture.view(),
+                image_layout: vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+            }];
+            let sampler_infos = [vk::DescriptorImageInfo {
+                sampler: texture.sampler(),
+                image_view: vk::ImageView::null(),
+                image_layout: vk::ImageLayout::UNDEFINED,
+            }];
+
+            device.write_descriptor_sets(&[
+                vk::WriteDescriptorSet::default()
+                    .dst_set(descriptor_set)
+                    .dst_binding(0)
+                    .dst_array_element(0)
+                    .descriptor_type(vk::DescriptorType::UNIFORM_BUFFER)
+                    .buffer_info(&buffer_infos),
+                vk::WriteDescriptorSet::default()
+                    .dst_set(descriptor_set)
+                    .dst_binding(1)
+                    .dst_array_element(0)
+                    .descriptor_type(vk::DescriptorType::SAMPLED_IMAGE)
+                    .image_info(&image_infos),
+                vk::WriteDescriptorSet::default()
+                    .dst_set(descriptor_set)
+                    .dst_binding(2)
+                    .dst_array_element(0)
+                    .descriptor_type(vk::DescriptorType::SAMPLER)
+                    .image_info(&sampler_infos),
+            ]);
+
+            uniform_buffers.push(uniform_buffer);
         }
 
         Ok(Self {
             device,
+            debug_utils,
+
+            render_area,
 
             descriptor_set_layout,
             pipeline_layout,
             pipeline,
 
             combined_buffer,
+            _texture: texture,
             uniform_buffers,
-            uniform_buffers_memory,
-            uniform_buffers_mapped,
             descriptor_pool,
             descriptor_sets,
 
-            start_time: std::time::Instant::now(),
+            time_source: Box::new(SystemTimeSource::new()),
         })
     }
 
+    pub fn set_time_source(&mut self, time_source: Box<dyn TimeSource>) {
+        self.time_source = time_source;
+    }
+
+    // swapchain 재생성 시 호출, 파이프라인과 버퍼, descriptor set은 그대로 유지
+    pub fn on_swapchain_recreated(&mut self, render_area: vk::Rect2D) {
+        self.render_area = render_area;
+    }
+
     fn update_uniform_buffer(
         &mut self,
         frame_idx: usize,
@@ -353,7 +419,7 @@ impl TestSubpass {
         window_height: u32,
         pre_transform: vk::SurfaceTransformFlagsKHR,
     ) {
-        let time = self.start_time.elapsed().as_secs_f32();
+        let time = self.time_source.elapsed_secs();
 
         // 모델 행렬: Z축 회전
         let model = glam::Mat4::from_rotation_z(-time.to_radians() * 90.0);
@@ -389,10 +455,7 @@ impl TestSubpass {
         let ubo = UniformBufferObject { model, view, proj };
 
         // 메모리에 데이터 복사
-        unsafe {
-            let data_ptr = self.uniform_buffers_mapped[frame_idx];
-            std::ptr::copy_nonoverlapping(&ubo, data_ptr as *mut UniformBufferObject, 1);
-        }
+        self.uniform_buffers[frame_idx].write(&ubo);
     }
 
     pub fn record_commands(
@@ -403,8 +466,24 @@ impl TestSubpass {
         window_height: u32,
         pre_transform: vk::SurfaceTransformFlagsKHR,
     ) {
+        self.debug_utils
+            .begin_label(command_buffer, "TestSubpass", SCENE_LABEL_COLOR);
+
         self.pipeline.bind_pipeline(command_buffer);
 
+        let viewport = vk::Viewport {
+            x: self.render_area.offset.x as f32,
+            y: self.render_area.offset.y as f32,
+            width: self.render_area.extent.width as f32,
+            height: self.render_area.extent.height as f32,
+            min_depth: 0.0,
+            max_depth: 1.0,
+        };
+
+        self.device.set_viewports(command_buffer, &[viewport]);
+        self.device
+            .set_scissors(command_buffer, &[self.render_area]);
+
         self.device.bind_vertex_buffers(
             command_buffer,
             &[self.combined_buffer.buffer],
@@ -414,7 +493,7 @@ impl TestSubpass {
         self.device.bind_index_buffer(
             command_buffer,
             self.combined_buffer.buffer,
-            vk::IndexType::UINT16,
+            self.combined_buffer.index_type,
             self.combined_buffer.index_offset,
         );
 
@@ -428,6 +507,8 @@ impl TestSubpass {
 
         self.device
             .draw_indexed(command_buffer, self.combined_buffer.index_count, 1, 0, 0, 0);
+
+        self.debug_utils.end_label(command_buffer);
     }
 }
 
@@ -437,13 +518,6 @@ impl Drop for TestSubpass {
 
         self.device.destroy_descriptor_pool(self.descriptor_pool);
 
-        for i in 0..MAX_FRAMES_IN_FLIGHT {
-            self.device.destroy_buffer_with_memory(
-                self.uniform_buffers[i],
-                self.uniform_buffers_memory[i],
-            );
-        }
-
         self.device
             .destroy_buffer_with_memory(self.combined_buffer.buffer, self.combined_buffer.memory);
         self.device.destroy_pipeline_layout(self.pipeline_layout);
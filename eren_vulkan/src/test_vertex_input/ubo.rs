@@ -1,9 +1,20 @@
+use bytemuck::{Pod, Zeroable};
 use glam::Mat4;
 
+use crate::shader_reflection::UniformLayout;
+
 #[repr(C, align(16))]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
 pub struct UniformBufferObject {
     pub model: Mat4,
     pub view: Mat4,
     pub proj: Mat4,
 }
+
+impl UniformLayout for UniformBufferObject {
+    const MEMBER_OFFSETS: &'static [u32] = &[
+        std::mem::offset_of!(UniformBufferObject, model) as u32,
+        std::mem::offset_of!(UniformBufferObject, view) as u32,
+        std::mem::offset_of!(UniformBufferObject, proj) as u32,
+    ];
+}
@@ -1,38 +1,12 @@
-use std::mem::offset_of;
-
-use ash::vk;
+use bytemuck::{Pod, Zeroable};
+use eren_vertex::VertexLayout;
 use glam::{Vec2, Vec3};
 
+// location은 필드 순서대로 0(pos), 1(color), 2(tex_coord)
 #[repr(C)]
-#[derive(Clone, Debug, Copy)]
+#[derive(Clone, Debug, Copy, Pod, Zeroable, VertexLayout)]
 pub struct Vertex {
-    pub pos: Vec2,
+    pub pos: Vec3,
     pub color: Vec3,
-}
-
-impl Vertex {
-    pub fn get_binding_description() -> vk::VertexInputBindingDescription {
-        vk::VertexInputBindingDescription {
-            binding: 0,
-            stride: size_of::<Self>() as u32,
-            input_rate: vk::VertexInputRate::VERTEX,
-        }
-    }
-
-    pub fn get_attribute_descriptions() -> [vk::VertexInputAttributeDescription; 2] {
-        [
-            vk::VertexInputAttributeDescription {
-                binding: 0,
-                location: 0,
-                format: vk::Format::R32G32_SFLOAT,
-                offset: offset_of!(Vertex, pos) as u32,
-            },
-            vk::VertexInputAttributeDescription {
-                binding: 0,
-                location: 1,
-                format: vk::Format::R32G32B32_SFLOAT,
-                offset: offset_of!(Vertex, color) as u32,
-            },
-        ]
-    }
+    pub tex_coord: Vec2,
 }
@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use ash::vk;
+use eren_vulkan_render_shared::{
+    command::CommandPool,
+    device::{
+        BufferWithMemoryCreationError, CopyCommandBufferError, Device, ImageViewCreationError,
+        ImageWithMemoryCreationError, MapMemoryError, MemoryUploadSlice, SamplerCreationError,
+    },
+};
+use thiserror::Error;
+
+use crate::debug_utils::DebugUtils;
+
+pub const TEXTURE_FORMAT: vk::Format = vk::Format::R8G8B8A8_SRGB;
+
+#[derive(Debug, Error)]
+pub enum TextureCreationError {
+    #[error("Failed to decode image: {0}")]
+    DecodeImage(#[from] image::ImageError),
+
+    #[error("Failed to create staging buffer: {0}")]
+    CreateStagingBuffer(#[from] BufferWithMemoryCreationError),
+
+    #[error("Failed to upload data to memory: {0}")]
+    UploadDataToMemory(#[from] MapMemoryError),
+
+    #[error("Failed to create image with memory: {0}")]
+    CreateImageWithMemory(#[from] ImageWithMemoryCreationError),
+
+    #[error("Failed to copy buffer to image: {0}")]
+    CopyBufferToImage(#[from] CopyCommandBufferError),
+
+    #[error("Failed to create image view: {0}")]
+    CreateImageView(#[from] ImageViewCreationError),
+
+    #[error("Failed to create sampler: {0}")]
+    CreateSampler(#[from] SamplerCreationError),
+}
+
+// 셰이더에서 샘플링하는 2D 텍스처와 샘플러
+pub struct Texture {
+    device: Arc<Device>,
+    image: vk::Image,
+    memory: vk::DeviceMemory,
+    view: vk::ImageView,
+    sampler: vk::Sampler,
+}
+
+impl Texture {
+    // PNG 등 이미지 파일 바이트를 RGBA8로 디코딩하여 업로드
+    pub fn from_image_bytes(
+        device: Arc<Device>,
+        command_pool: &CommandPool,
+        bytes: &[u8],
+        debug_utils: &DebugUtils,
+    ) -> Result<Self, TextureCreationError> {
+        let image = image::load_from_memory(bytes)?.to_rgba8();
+        let (width, height) = image.dimensions();
+
+        Self::from_rgba8(device, command_pool, &image, width, height, debug_utils)
+    }
+
+    // 텍스처를 사용하지 않는 장면에서 색을 그대로 유지하기 위한 1x1 흰색 텍스처
+    pub fn white(
+        device: Arc<Device>,
+        command_pool: &CommandPool,
+        debug_utils: &DebugUtils,
+    ) -> Result<Self, TextureCreationError> {
+        Self::from_rgba8(
+            device,
+            command_pool,
+            &[255, 255, 255, 255],
+            1,
+            1,
+            debug_utils,
+        )
+    }
+
+    fn from_rgba8(
+        device: Arc<Device>,
+        command_pool: &CommandPool,
+        pixels: &[u8],
+        width: u32,
+        height: u32,
+        debug_utils: &DebugUtils,
+    ) -> Result<Self, TextureCreationError> {
+        let size = pixels.len() as vk::DeviceSize;
+
+        let (staging_buffer, staging_memory) = device.create_buffer_with_memory(
+            size,
+            vk::BufferUsageFlags::TRANSFER_SRC,
+            vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT,
+        )?;
+        debug_utils.set_name(staging_buffer, "Texture staging buffer");
+
+        // 실패해도 staging buffer는 항상 해제
+        let result = upload_to_image(
+            &device,
+            command_pool,
+            staging_buffer,
+            staging_memory,
+            pixels,
+            width,
+            height,
+        );
+        device.destroy_buffer_with_memory(staging_buffer, staging_memory);
+        let (image, memory) = result?;
+        debug_utils.set_name(image, "Texture image");
+
+        let view =
+            match device.create_image_view(image, TEXTURE_FORMAT, vk::ImageAspectFlags::COLOR) {
+                Ok(view) => view,
+                Err(e) => {
+                    device.destroy_image_with_memory(image, memory);
+                    return Err(e.into());
+                }
+            };
+
+        let sampler_info = vk::SamplerCreateInfo::default()
+            .mag_filter(vk::Filter::LINEAR)
+            .min_filter(vk::Filter::LINEAR)
+            .mipmap_mode(vk::SamplerMipmapMode::NEAREST)
+            .address_mode_u(vk::SamplerAddressMode::CLAMP_TO_EDGE)
+            .address_mode_v(vk::SamplerAddressMode::CLAMP_TO_EDGE)
+            .address_mode_w(vk::SamplerAddressMode::CLAMP_TO_EDGE)
+            .anisotropy_enable(false)
+            .max_lod(0.0);
+
+        let sampler = match device.create_sampler(&sampler_info) {
+            Ok(sampler) => sampler,
+            Err(e) => {
+                device.destroy_image_view(view);
+                device.destroy_image_with_memory(image, memory);
+                return Err(e.into());
+            }
+        };
+
+        Ok(Self {
+            device,
+            image,
+            memory,
+            view,
+            sampler,
+        })
+    }
+
+    pub fn view(&self) -> vk::ImageView {
+        self.view
+    }
+
+    pub fn sampler(&self) -> vk::Sampler {
+        self.sampler
+    }
+}
+
+// staging buffer에 픽셀을 쓰고 device-local 이미지로 복사
+// 복사 후 이미지는 SHADER_READ_ONLY_OPTIMAL 레이아웃
+fn upload_to_image(
+    device: &Device,
+    command_pool: &CommandPool,
+    staging_buffer: vk::Buffer,
+    staging_memory: vk::DeviceMemory,
+    pixels: &[u8],
+    width: u32,
+    height: u32,
+) -> Result<(vk::Image, vk::DeviceMemory), TextureCreationError> {
+    device.upload_slices_to_memory(
+        staging_memory,
+        pixels.len() as vk::DeviceSize,
+        &[MemoryUploadSlice {
+            src: pixels,
+            dst_offset: 0,
+        }],
+    )?;
+
+    let (image, memory) = device.create_image_with_memory(
+        width,
+        height,
+        TEXTURE_FORMAT,
+        vk::ImageUsageFlags::TRANSFER_DST | vk::ImageUsageFlags::SAMPLED,
+        vk::MemoryPropertyFlags::DEVICE_LOCAL,
+    )?;
+
+    if let Err(e) = command_pool.copy_buffer_to_image(staging_buffer, image, width, height) {
+        device.destroy_image_with_memory(image, memory);
+        return Err(e.into());
+    }
+
+    Ok((image, memory))
+}
+
+impl Drop for Texture {
+    fn drop(&mut self) {
+        self.device.destroy_sampler(self.sampler);
+        self.device.destroy_image_view(self.view);
+        self.device
+            .destroy_image_with_memory(self.image, self.memory);
+    }
+}
@@ -0,0 +1,79 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use ash::vk;
+use bytemuck::Pod;
+use eren_vulkan_render_shared::device::{BufferWithMemoryCreationError, Device, MapMemoryError};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum UniformBufferCreationError {
+    #[error("Failed to create buffer with memory: {0}")]
+    CreateBufferWithMemory(#[from] BufferWithMemoryCreationError),
+
+    #[error("Failed to map memory: {0}")]
+    MapMemory(#[from] MapMemoryError),
+}
+
+// 값 하나를 담는 host visible uniform buffer, 생성 시 매핑한 메모리를 해제할 때까지 유지
+// T: Pod이므로 패딩이나 포인터가 있는 타입은 컴파일 에러
+pub struct UniformBuffer<T: Pod> {
+    device: Arc<Device>,
+    buffer: vk::Buffer,
+    memory: vk::DeviceMemory,
+    mapped: *mut u8,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Pod> UniformBuffer<T> {
+    pub fn new(device: Arc<Device>) -> Result<Self, UniformBufferCreationError> {
+        let size = Self::size();
+
+        let (buffer, memory) = device.create_buffer_with_memory(
+            size,
+            vk::BufferUsageFlags::UNIFORM_BUFFER,
+            vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT,
+        )?;
+
+        let mapped = match device.map_memory(memory, size) {
+            Ok(mapped) => mapped as *mut u8,
+            Err(e) => {
+                device.destroy_buffer_with_memory(buffer, memory);
+                return Err(e.into());
+            }
+        };
+
+        Ok(Self {
+            device,
+            buffer,
+            memory,
+            mapped,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn size() -> vk::DeviceSize {
+        std::mem::size_of::<T>() as vk::DeviceSize
+    }
+
+    pub fn buffer(&self) -> vk::Buffer {
+        self.buffer
+    }
+
+    // HOST_COHERENT 메모리이므로 flush 없이 다음 submit부터 반영됨
+    pub fn write(&mut self, value: &T) {
+        let src = bytemuck::bytes_of(value);
+
+        // mapped는 size_of::<T>() 바이트로 매핑되어 memory를 해제할 때까지 유효하고,
+        // &mut self로 동시에 쓰는 곳이 없음
+        let dst = unsafe { std::slice::from_raw_parts_mut(self.mapped, src.len()) };
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<T: Pod> Drop for UniformBuffer<T> {
+    fn drop(&mut self) {
+        self.device.unmap_memory(self.memory);
+        self.device
+            .destroy_buffer_with_memory(self.buffer, self.memory);
+    }
+}
@@ -0,0 +1,283 @@
+use std::{
+    ffi::{CStr, c_void},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use ash::vk;
+use eren_vulkan_render_shared::instance::Instance;
+use thiserror::Error;
+
+use crate::debug_utils::DEBUG_UTILS_COMPILED;
+
+// validation layer 사용 여부 (예: EREN_VULKAN_VALIDATION=1)
+// 실행 시 환경 변수를 설정할 수 없는 Android에서는 빌드할 때 설정한 값을 사용
+pub const VALIDATION_ENV: &str = "EREN_VULKAN_VALIDATION";
+
+const VALIDATION_LAYER: &CStr = c"VK_LAYER_KHRONOS_validation";
+
+const LOG_TARGET: &str = "vulkan";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationMode {
+    Disabled,
+    // 메시지를 로그로만 출력
+    Enabled,
+    // 테스트용, 에러가 보고되면 headless 렌더링과 golden 테스트를 실패 처리
+    Strict,
+}
+
+impl ValidationMode {
+    pub fn from_env() -> Self {
+        let value = std::env::var(VALIDATION_ENV)
+            .ok()
+            .or_else(|| option_env!("EREN_VULKAN_VALIDATION").map(str::to_string));
+        let Some(value) = value else {
+            return ValidationMode::Disabled;
+        };
+
+        match value.trim() {
+            "" | "0" => ValidationMode::Disabled,
+            "1" => ValidationMode::Enabled,
+            "strict" => ValidationMode::Strict,
+            _ => {
+                log::warn!(
+                    "Invalid {} value: {:?}, expected 0, 1 or strict",
+                    VALIDATION_ENV,
+                    value
+                );
+                ValidationMode::Disabled
+            }
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum DebugMessengerCreationError {
+    #[error("Failed to create debug utils messenger: {0}")]
+    CreateDebugUtilsMessenger(#[from] vk::Result),
+}
+
+#[derive(Debug, Error)]
+#[error("{0} Vulkan validation error(s) were reported")]
+pub struct ValidationErrors(pub usize);
+
+// 인스턴스를 만들 때 켤 layer와 extension
+pub struct Validation {
+    pub mode: ValidationMode,
+    pub layers: Vec<&'static CStr>,
+    pub extensions: Vec<&'static CStr>,
+}
+
+impl Validation {
+    // layer나 VK_EXT_debug_utils가 없으면 경고를 남기고 validation 없이 진행
+    // 디버그 빌드 또는 debug-utils feature에서는 validation과 관계없이 객체 이름용으로 VK_EXT_debug_utils를 요청
+    pub fn from_env() -> Self {
+        let mut mode = ValidationMode::from_env();
+        let mut validation = Self {
+            mode: ValidationMode::Disabled,
+            layers: Vec::new(),
+            extensions: Vec::new(),
+        };
+
+        if mode == ValidationMode::Disabled && !DEBUG_UTILS_COMPILED {
+            return validation;
+        }
+
+        let entry = match unsafe { ash::Entry::load() } {
+            Ok(entry) => entry,
+            Err(e) => {
+                log::warn!("Failed to load Vulkan loader: {}", e);
+                return validation;
+            }
+        };
+
+        if mode != ValidationMode::Disabled && !has_layer(&entry, VALIDATION_LAYER) {
+            // Android에서는 libVkLayer_khronos_validation.so를 jniLibs에 포함해야 함
+            log::warn!(
+                "{} is not installed, validation disabled",
+                VALIDATION_LAYER.to_string_lossy()
+            );
+            mode = ValidationMode::Disabled;
+        }
+
+        // loader 또는 validation layer가 제공
+        let debug_utils = has_extension(&entry, None, ash::ext::debug_utils::NAME)
+            || (mode != ValidationMode::Disabled
+                && has_extension(&entry, Some(VALIDATION_LAYER), ash::ext::debug_utils::NAME));
+
+        if mode != ValidationMode::Disabled && !debug_utils {
+            log::warn!(
+                "{} is not available, validation disabled",
+                ash::ext::debug_utils::NAME.to_string_lossy()
+            );
+            mode = ValidationMode::Disabled;
+        }
+
+        if mode != ValidationMode::Disabled {
+            log::info!("Vulkan validation enabled ({:?})", mode);
+
+            validation.mode = mode;
+            validation.layers.push(VALIDATION_LAYER);
+        }
+
+        if debug_utils && (mode != ValidationMode::Disabled || DEBUG_UTILS_COMPILED) {
+            validation.extensions.push(ash::ext::debug_utils::NAME);
+        }
+
+        validation
+    }
+
+    pub fn create_messenger(
+        &self,
+        instance: &Arc<Instance>,
+    ) -> Result<Option<DebugMessenger>, DebugMessengerCreationError> {
+        if self.mode == ValidationMode::Disabled {
+            return Ok(None);
+        }
+
+        DebugMessenger::new(instance.clone()).map(Some)
+    }
+
+    // Strict 모드에서 messenger가 지금까지 받은 validation 에러가 있으면 실패
+    pub fn check_errors(&self, messenger: Option<&DebugMessenger>) -> Result<(), ValidationErrors> {
+        let errors = messenger.map_or(0, DebugMessenger::error_count);
+
+        if self.mode == ValidationMode::Strict && errors > 0 {
+            return Err(ValidationErrors(errors));
+        }
+
+        Ok(())
+    }
+}
+
+fn has_layer(entry: &ash::Entry, name: &CStr) -> bool {
+    let layers = unsafe { entry.enumerate_instance_layer_properties() }.unwrap_or_default();
+
+    layers
+        .iter()
+        .any(|layer| layer.layer_name_as_c_str() == Ok(name))
+}
+
+fn has_extension(entry: &ash::Entry, layer: Option<&CStr>, name: &CStr) -> bool {
+    let extensions =
+        unsafe { entry.enumerate_instance_extension_properties(layer) }.unwrap_or_default();
+
+    extensions
+        .iter()
+        .any(|extension| extension.extension_name_as_c_str() == Ok(name))
+}
+
+// validation layer 메시지를 log 크레이트로 전달, 인스턴스보다 먼저 해제되어야 함
+pub struct DebugMessenger {
+    _instance: Arc<Instance>,
+    loader: ash::ext::debug_utils::Instance,
+    messenger: vk::DebugUtilsMessengerEXT,
+    // 콜백의 user data로 넘기므로 messenger가 해제될 때까지 주소가 바뀌지 않아야 함
+    // 컨텍스트마다 따로 세어 여러 인스턴스가 있어도 서로의 에러를 세지 않음
+    error_count: Box<AtomicUsize>,
+}
+
+impl DebugMessenger {
+    pub fn new(instance: Arc<Instance>) -> Result<Self, DebugMessengerCreationError> {
+        let loader = ash::ext::debug_utils::Instance::new(instance.entry(), instance.raw());
+        let error_count = Box::new(AtomicUsize::new(0));
+
+        let info = vk::DebugUtilsMessengerCreateInfoEXT::default()
+            .message_severity(
+                vk::DebugUtilsMessageSeverityFlagsEXT::VERBOSE
+                    | vk::DebugUtilsMessageSeverityFlagsEXT::INFO
+                    | vk::DebugUtilsMessageSeverityFlagsEXT::WARNING
+                    | vk::DebugUtilsMessageSeverityFlagsEXT::ERROR,
+            )
+            .message_type(
+                vk::DebugUtilsMessageTypeFlagsEXT::GENERAL
+                    | vk::DebugUtilsMessageTypeFlagsEXT::VALIDATION
+                    | vk::DebugUtilsMessageTypeFlagsEXT::PERFORMANCE,
+            )
+            .pfn_user_callback(Some(debug_callback))
+            .user_data(std::ptr::from_ref(&*error_count).cast_mut().cast());
+
+        let messenger = unsafe { loader.create_debug_utils_messenger(&info, None) }?;
+
+        Ok(Self {
+            _instance: instance,
+            loader,
+            messenger,
+            error_count,
+        })
+    }
+
+    // 이 messenger가 받은 ERROR 메시지 수
+    pub fn error_count(&self) -> usize {
+        self.error_count.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for DebugMessenger {
+    fn drop(&mut self) {
+        unsafe {
+            self.loader
+                .destroy_debug_utils_messenger(self.messenger, None);
+        }
+    }
+}
+
+// INFO는 loader의 layer/ICD 탐색 메시지가 대부분이라 debug로 낮춤
+fn log_level(severity: vk::DebugUtilsMessageSeverityFlagsEXT) -> log::Level {
+    if severity.contains(vk::DebugUtilsMessageSeverityFlagsEXT::ERROR) {
+        log::Level::Error
+    } else if severity.contains(vk::DebugUtilsMessageSeverityFlagsEXT::WARNING) {
+        log::Level::Warn
+    } else if severity.contains(vk::DebugUtilsMessageSeverityFlagsEXT::INFO) {
+        log::Level::Debug
+    } else {
+        log::Level::Trace
+    }
+}
+
+// 드라이버 스레드에서 호출될 수 있으며, panic이 FFI 경계를 넘지 않도록 로그와 카운터만 갱신
+unsafe extern "system" fn debug_callback(
+    message_severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    message_type: vk::DebugUtilsMessageTypeFlagsEXT,
+    p_callback_data: *const vk::DebugUtilsMessengerCallbackDataEXT<'_>,
+    p_user_data: *mut c_void,
+) -> vk::Bool32 {
+    let level = log_level(message_severity);
+
+    // user data는 DebugMessenger가 소유한 카운터이며 messenger보다 오래 유지됨
+    if level == log::Level::Error
+        && let Some(error_count) = unsafe { p_user_data.cast::<AtomicUsize>().as_ref() }
+    {
+        error_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    // p_callback_data와 문자열은 콜백이 반환될 때까지 유효함
+    let (id_name, message) = match unsafe { p_callback_data.as_ref() } {
+        Some(data) => unsafe {
+            (
+                data.message_id_name_as_c_str()
+                    .map(CStr::to_string_lossy)
+                    .unwrap_or_default(),
+                data.message_as_c_str()
+                    .map(CStr::to_string_lossy)
+                    .unwrap_or_default(),
+            )
+        },
+        None => Default::default(),
+    };
+
+    log::log!(
+        target: LOG_TARGET,
+        level,
+        "[{:?}] {} {}",
+        message_type,
+        id_name,
+        message
+    );
+
+    // 호출을 중단하지 않음 (명세상 항상 VK_FALSE)
+    vk::FALSE
+}
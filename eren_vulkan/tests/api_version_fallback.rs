@@ -0,0 +1,20 @@
+// API 버전 상한을 1.1로 낮춰 vkCreateRenderPass(v1) 경로를 검사
+// 환경 변수를 바꾸므로 다른 테스트와 프로세스를 공유하지 않도록 별도 파일에 둠
+#[test]
+#[ignore = "requires a Vulkan driver such as lavapipe"]
+fn renders_with_render_pass_v1_at_api_1_1() {
+    unsafe { std::env::set_var("EREN_VULKAN_API_VERSION", "1.1") };
+
+    let path = std::env::temp_dir().join("eren_vulkan_api_1_1.png");
+    let report = match eren_vulkan_mobile_test::render_headless(64, 64, &path) {
+        Ok(report) => report,
+        Err(e) => panic!("Headless render failed at Vulkan 1.1: {}", e),
+    };
+
+    assert_eq!(report.api_version, "1.1.0");
+    assert!(!report.render_pass2);
+    assert!(report.downgraded_features.contains(&"Vulkan 1.3"));
+    assert!(report.downgraded_features.contains(&"vkCreateRenderPass2"));
+
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,10 @@
+// Vulkan 드라이버가 필요하므로 기본으로는 실행하지 않음
+// lavapipe 등이 설치된 환경에서 cargo test -- --ignored로 실행
+// 기준 이미지(golden/)가 없으면 MissingReference로 실패하므로 lavapipe에서 --golden --update로 생성해 커밋
+#[test]
+#[ignore = "requires a Vulkan driver such as lavapipe"]
+fn golden_images_match() {
+    if let Err(e) = eren_vulkan_mobile_test::run_golden_tests(false) {
+        panic!("Golden image test failed: {}", e);
+    }
+}
@@ -0,0 +1,18 @@
+// validation layer를 Strict 모드로 켜고 headless 렌더링 중 ERROR 메시지가 없는지 검사
+// 환경 변수를 바꾸므로 다른 테스트와 프로세스를 공유하지 않도록 별도 파일에 둠
+#[test]
+#[ignore = "requires a Vulkan driver and VK_LAYER_KHRONOS_validation"]
+fn headless_render_has_no_validation_errors() {
+    unsafe { std::env::set_var("EREN_VULKAN_VALIDATION", "strict") };
+
+    let path = std::env::temp_dir().join("eren_vulkan_validation.png");
+    let report = match eren_vulkan_mobile_test::render_headless(64, 64, &path) {
+        Ok(report) => report,
+        Err(e) => panic!("Headless render failed with validation: {}", e),
+    };
+
+    // layer가 없어 validation 없이 실행되었다면 검사한 것이 없으므로 실패
+    assert!(report.strict_validation);
+
+    let _ = std::fs::remove_file(&path);
+}